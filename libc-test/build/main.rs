@@ -6,6 +6,7 @@ mod target;
 use std::env;
 use std::env::VarError;
 use std::io::Write;
+use std::path::Path;
 use std::process::{
     Command,
     Stdio,
@@ -42,12 +43,22 @@ fn do_cc(t: &Target) {
             cc::Build::new().file("src/makedev.c").compile("makedev");
         }
     }
+    if (t.families.iter().any(|x| x == "unix") || t.cygwin() || t.win())
+        && !(t.redox() || t.wasi() || t.wali() || t.qurt())
+    {
+        cc::Build::new().file("src/errno.c").compile("errno");
+    }
     if (t.android() || t.linux()) && !t.wali() {
         cc::Build::new().file("src/errqueue.c").compile("errqueue");
     }
     if t.linux() && !t.wali() {
+        cc::Build::new().file("src/elf.c").compile("elf");
         cc::Build::new().file("src/nlmsg.c").compile("nlmsg");
     }
+    if (t.linux() || t.android()) && !t.wali() {
+        cc::Build::new().file("src/cpuset.c").compile("cpuset");
+        cc::Build::new().file("src/siginfo.c").compile("siginfo");
+    }
     if (t.linux() || t.l4re() || t.android() || t.emscripten() || t.solaris() || t.illumos())
         && !t.wali()
     {
@@ -2139,7 +2150,9 @@ fn test_android(t: &Target) {
 
     cfg.rename_struct_ty(|ty| match ty {
         // Just pass all these through, no need for a "struct" prefix
-        "FILE" | "fd_set" | "Dl_info" | "Elf32_Phdr" | "Elf64_Phdr" => Some(ty.to_string()),
+        "FILE" | "fd_set" | "Dl_info" | "Elf32_Phdr" | "Elf64_Phdr" | "Elf32_Dyn" | "Elf64_Dyn" => {
+            Some(ty.to_string())
+        }
         t if t.ends_with("_t") => Some(t.to_string()),
         _ => None,
     });
@@ -2438,6 +2451,10 @@ fn test_android(t: &Target) {
             ("signalfd_siginfo", "ssi_call_addr") => true,
             // FIXME(android): Seems the type has been changed on NDK r26b
             ("flock64", "l_start" | "l_len") => true,
+            // `d_un` is an anonymous union
+            ("Elf32_Dyn" | "Elf64_Dyn", "d_un") => true,
+            // `r_state` is an anonymous enum
+            ("r_debug", "r_state") => true,
             _ => false,
         }
     });
@@ -2460,6 +2477,9 @@ fn test_android(t: &Target) {
             // uc_sigmask and uc_sigmask64 are an anonymous union
             ("ucontext_t", "uc_sigmask" | "uc_sigmask64" | "uc_sigmask__c_anonymous_union") => true,
 
+            // `addr` is a flexible array member
+            ("tun_filter", "addr") => true,
+
             _ => false,
         }
     });
@@ -3941,6 +3961,11 @@ fn test_linux(t: &Target) {
     let mips32 = t.mips32();
     let pauthtest = t.pauthtest();
     let p32 = t.p32();
+    // BlueZ's headers are installed in the x86 glibc CI images. Outside of CI, only check the
+    // Bluetooth definitions if the headers are available.
+    let bluez = gnu
+        && (x86_64 || x86_32)
+        && (env_flag("LIBC_CI") || Path::new("/usr/include/bluetooth/bluetooth.h").exists());
     let versions = &*VERSIONS;
     let kernel = match versions.linux {
         Some(v) => v,
@@ -4031,6 +4056,7 @@ fn test_linux(t: &Target) {
         "regex.h",
         "resolv.h",
         "sched.h",
+        (!l4re, "scsi/sg.h"),
         "semaphore.h",
         "shadow.h",
         "signal.h",
@@ -4111,17 +4137,35 @@ fn test_linux(t: &Target) {
     // Include linux headers at the end:
     headers!(
         cfg,
-        ((loongarch64 || riscv64) && !l4re, "asm/hwcap.h"),
+        (ppc && !l4re, "asm/cputable.h"),
+        (
+            (aarch64 || arm32 || loongarch64 || riscv64) && !l4re,
+            "asm/hwcap.h"
+        ),
+        (riscv64 && kernel >= (6, 4), "asm/hwprobe.h"),
+        ((x86_64 || x86_32) && !l4re, "asm/hwcap2.h"),
         (!l4re, "asm/mman.h"),
     );
 
+    headers!(
+        cfg,
+        (bluez, "bluetooth/bluetooth.h"),
+        (bluez, "bluetooth/hci.h"),
+        (bluez, "bluetooth/l2cap.h"),
+        (bluez, "bluetooth/rfcomm.h"),
+        (bluez, "bluetooth/sco.h"),
+    );
+
     if !wasm32 && !l4re {
         headers!(
             cfg,
             (gnu, "linux/aio_abi.h"),
+            "linux/auxvec.h",
+            "linux/bsg.h",
             "linux/can.h",
             "linux/can/bcm.h",
             "linux/can/error.h",
+            "linux/can/isotp.h",
             "linux/can/j1939.h",
             "linux/can/netlink.h",
             "linux/can/raw.h",
@@ -4129,6 +4173,8 @@ fn test_linux(t: &Target) {
             "linux/connector.h",
             "linux/dccp.h",
             "linux/errqueue.h",
+            "linux/ethtool.h",
+            "linux/ethtool_netlink.h",
             "linux/falloc.h",
             "linux/filter.h",
             "linux/fs.h",
@@ -4142,6 +4188,7 @@ fn test_linux(t: &Target) {
             "linux/if_tun.h",
             "linux/if_xdp.h",
             "linux/input.h",
+            "linux/ioprio.h",
             "linux/ipv6.h",
             "linux/kexec.h",
             "linux/keyctl.h",
@@ -4152,8 +4199,10 @@ fn test_linux(t: &Target) {
             "linux/mman.h",
             "linux/module.h",
             "linux/mount.h",
+            "linux/mptcp.h",
             "linux/net_tstamp.h",
             "linux/netfilter/nfnetlink.h",
+            "linux/netfilter/nfnetlink_conntrack.h",
             "linux/netfilter/nfnetlink_log.h",
             "linux/netfilter/nfnetlink_queue.h",
             "linux/netfilter/nf_tables.h",
@@ -4164,6 +4213,7 @@ fn test_linux(t: &Target) {
             "linux/netfilter_ipv6/ip6_tables.h",
             "linux/netlink.h",
             "linux/nsfs.h",
+            "linux/nvme_ioctl.h",
             "linux/openat2.h",
             // FIXME(linux): some items require Linux >= 5.6:
             "linux/ptp_clock.h",
@@ -4171,6 +4221,8 @@ fn test_linux(t: &Target) {
             "linux/quota.h",
             "linux/random.h",
             "linux/reboot.h",
+            "linux/rseq.h",
+            "linux/rtc.h",
             "linux/rtnetlink.h",
             "linux/sched.h",
             "linux/sctp.h",
@@ -4178,16 +4230,23 @@ fn test_linux(t: &Target) {
             "linux/securebits.h",
             "linux/sock_diag.h",
             "linux/sockios.h",
+            "linux/time_types.h",
             "linux/tls.h",
             "linux/uinput.h",
+            "linux/virtio_net.h",
             "linux/vm_sockets.h",
             "linux/wait.h",
+            "linux/watchdog.h",
             "linux/wireless.h",
             "sys/fanotify.h",
             "sys/auxv.h",
             (gnu || musl, "linux/close_range.h"),
+            (gnu && versions.glibc.unwrap() >= (2, 35), "sys/rseq.h"),
+            (
+                riscv64 && gnu && versions.glibc.unwrap() >= (2, 40),
+                "sys/hwprobe.h"
+            ),
             (uclibc, "linux/fanotify.h"),
-            (uclibc, "linux/auxvec.h"),
             (uclibc, "linux/close_range.h"),
             (uclibc, "linux/if_packet.h"),
             (uclibc, "linux/elf-em.h"),
@@ -4220,11 +4279,28 @@ fn test_linux(t: &Target) {
         "Elf64_Ehdr",
         "Elf32_Chdr",
         "Elf64_Chdr",
+        "Elf32_Dyn",
+        "Elf64_Dyn",
+        "Elf32_Nhdr",
+        "Elf64_Nhdr",
+        "Elf32_Verdef",
+        "Elf64_Verdef",
+        "Elf32_Verdaux",
+        "Elf64_Verdaux",
+        "Elf32_Verneed",
+        "Elf64_Verneed",
+        "Elf32_Vernaux",
+        "Elf64_Vernaux",
+        "Dl_serpath",
+        "Dl_serinfo",
     ];
     // typedefs don't need any keywords
     cfg.rename_struct_ty(move |ty| typedef_structs.contains(&ty).then_some(ty.to_string()))
         .rename_struct_ty(|ty| ty.ends_with("_t").then_some(ty.to_string()))
-        .rename_union_ty(|ty| ty.ends_with("_t").then_some(ty.to_string()));
+        // `La_*` are the per-architecture register sets of the `LD_AUDIT` interface
+        .rename_struct_ty(|ty| ty.starts_with("La_").then_some(ty.to_string()))
+        .rename_union_ty(|ty| ty.ends_with("_t").then_some(ty.to_string()))
+        .rename_union_ty(|ty| ty.starts_with("La_").then_some(ty.to_string()));
 
     cfg.rename_type(move |ty| {
         match ty {
@@ -4250,7 +4326,9 @@ fn test_linux(t: &Target) {
             // The following structs have a field called `type` in C,
             // but `type` is a Rust keyword, so these fields are translated
             // to `type_` in Rust.
-            ("input_event" | "input_mask" | "ff_effect", "type_") => Some("type".to_string()),
+            ("input_event" | "input_mask" | "ff_effect" | "hci_dev_info", "type_") => {
+                Some("type".to_string())
+            }
 
             // FIXME(1.0): field has a different name on loongarch
             ("ucontext_t", "uc_flags") if loongarch64 => Some("__uc_flags".to_string()),
@@ -4289,6 +4367,16 @@ fn test_linux(t: &Target) {
             // specific type.
             "Ioctl" => true,
 
+            // A `#define` for `struct nvme_passthru_cmd` rather than a typedef.
+            "nvme_admin_cmd" => true,
+
+            // `LD_AUDIT` function signatures, which have no C typedef.
+            t if t.starts_with("la_") => true,
+            // GCC vector types, which are more aligned than our arrays.
+            "La_x86_64_xmm" | "La_x86_64_ymm" | "La_x86_64_zmm" => true,
+            // Defined with `#define`
+            "La_x32_regs" | "La_x32_retval" => true,
+
             t => {
                 if musl {
                     // LFS64 types have been removed in musl 1.2.4+
@@ -4378,6 +4466,23 @@ fn test_linux(t: &Target) {
             // `xsk_tx_metadata_request` and `xsk_tx_metadata_completion`.
             "xsk_tx_metadata" | "xsk_tx_metadata_request" | "xsk_tx_metadata_completion" => true,
 
+            // Added in kernel 6.6
+            "proc_input" => kernel < (6, 6),
+
+            // Fields were added in kernel 6.5 and 6.10
+            "mptcp_info" => kernel < (6, 10),
+            "mptcp_full_info" => kernel < (6, 5),
+            "can_raw_vcid_options" => kernel < (6, 9),
+            // `node_id` and `mm_cid` were added in kernel 6.3
+            "rseq" => kernel < (6, 3),
+
+            // The libc copies in `netinet/tcp.h` only have the first three fields, and
+            // `<linux/tcp.h>` can't be included alongside it. These are tested in the
+            // `linux_tcp.rs` file.
+            "tcp_zerocopy_receive" => true,
+            "tcp_diag_md5sig" if !musl => true,
+            "tcp_ao_add" | "tcp_ao_info_opt" => true,
+
             // A new field was added in kernel 5.4, this is the old version for backwards compatibility.
             // https://github.com/torvalds/linux/commit/77cd0d7b3f257fd0e3096b4fdcff1a7d38e99e10
             "xdp_ring_offset_v1" | "xdp_mmap_offsets_v1" => true,
@@ -4394,6 +4499,7 @@ fn test_linux(t: &Target) {
             // as `<sched.h>`, the `struct sched_param` is defined twice, causing the compilation to
             // fail. The problem doesn't seem to be present in more recent versions of the linux
             // kernel so we can drop this and test the type once this new version is used in CI.
+            // The type is tested in the `linux_sched_types.rs` file.
             "sched_attr" => true,
 
             // Recent additions
@@ -4438,6 +4544,30 @@ fn test_linux(t: &Target) {
             // Many more fields added in the glibc update to go with 6.17
             "tcp_info" if gnu && versions.glibc.unwrap() < (2, 43) => true,
 
+            // Added in glibc 2.35
+            "dl_find_object" if gnu && versions.glibc.unwrap() < (2, 35) => true,
+            // AArch64 signal frame records added in kernel 5.19 and 6.3, not in musl's headers
+            "za_context" => musl || kernel < (5, 19),
+            "tpidr2_context" | "zt_context" => musl || kernel < (6, 3),
+            "riscv_hwprobe" => kernel < (6, 4),
+            // The AArch64 audit interface changed in glibc 2.35
+            "La_aarch64_regs" | "La_aarch64_retval" | "La_aarch64_vector"
+                if gnu && versions.glibc.unwrap() < (2, 35) =>
+            {
+                true
+            }
+
+            // The BlueZ headers are only available on some targets.
+            "bdaddr_t" | "bt_security" | "bt_power" | "bt_voice" | "sockaddr_hci"
+            | "hci_filter" | "hci_dev_req" | "hci_dev_list_req" | "hci_dev_stats"
+            | "hci_dev_info" | "sockaddr_l2" | "l2cap_options" | "l2cap_conninfo"
+            | "sockaddr_rc" | "rfcomm_conninfo" | "sockaddr_sco" | "sco_options"
+            | "sco_conninfo"
+                if !bluez =>
+            {
+                true
+            }
+
             _ => false,
         }
     });
@@ -4459,6 +4589,109 @@ fn test_linux(t: &Target) {
             return true;
         }
 
+        // The BlueZ headers are only available on some targets.
+        if !bluez
+            && matches!(
+                name,
+                "BTPROTO_L2CAP"
+                    | "BTPROTO_HCI"
+                    | "BTPROTO_SCO"
+                    | "BTPROTO_RFCOMM"
+                    | "BTPROTO_BNEP"
+                    | "BTPROTO_CMTP"
+                    | "BTPROTO_HIDP"
+                    | "BTPROTO_AVDTP"
+                    | "BTPROTO_ISO"
+                    | "SOL_HCI"
+                    | "SOL_L2CAP"
+                    | "SOL_SCO"
+                    | "SOL_RFCOMM"
+                    | "BT_SECURITY"
+                    | "BT_SECURITY_SDP"
+                    | "BT_SECURITY_LOW"
+                    | "BT_SECURITY_MEDIUM"
+                    | "BT_SECURITY_HIGH"
+                    | "BT_SECURITY_FIPS"
+                    | "BT_DEFER_SETUP"
+                    | "BT_FLUSHABLE"
+                    | "BT_FLUSHABLE_OFF"
+                    | "BT_FLUSHABLE_ON"
+                    | "BT_POWER"
+                    | "BT_POWER_FORCE_ACTIVE_OFF"
+                    | "BT_POWER_FORCE_ACTIVE_ON"
+                    | "BT_CHANNEL_POLICY"
+                    | "BT_CHANNEL_POLICY_BREDR_ONLY"
+                    | "BT_CHANNEL_POLICY_BREDR_PREFERRED"
+                    | "BT_CHANNEL_POLICY_AMP_PREFERRED"
+                    | "BT_VOICE"
+                    | "BT_VOICE_TRANSPARENT"
+                    | "BT_VOICE_CVSD_16BIT"
+                    | "BT_SNDMTU"
+                    | "BT_RCVMTU"
+                    | "BDADDR_BREDR"
+                    | "BDADDR_LE_PUBLIC"
+                    | "BDADDR_LE_RANDOM"
+                    | "HCI_MAX_DEV"
+                    | "HCI_DEV_NONE"
+                    | "HCI_CHANNEL_RAW"
+                    | "HCI_CHANNEL_USER"
+                    | "HCI_CHANNEL_MONITOR"
+                    | "HCI_CHANNEL_CONTROL"
+                    | "HCI_CHANNEL_LOGGING"
+                    | "HCI_DATA_DIR"
+                    | "HCI_FILTER"
+                    | "HCI_TIME_STAMP"
+                    | "HCI_CMSG_DIR"
+                    | "HCI_CMSG_TSTAMP"
+                    | "HCI_FLT_TYPE_BITS"
+                    | "HCI_FLT_EVENT_BITS"
+                    | "HCI_FLT_OCF_BITS"
+                    | "HCI_FLT_OGF_BITS"
+                    | "HCIDEVUP"
+                    | "HCIDEVDOWN"
+                    | "HCIDEVRESET"
+                    | "HCIDEVRESTAT"
+                    | "HCIGETDEVLIST"
+                    | "HCIGETDEVINFO"
+                    | "HCIGETCONNLIST"
+                    | "HCIGETCONNINFO"
+                    | "HCIGETAUTHINFO"
+                    | "HCISETRAW"
+                    | "HCISETSCAN"
+                    | "HCISETAUTH"
+                    | "HCISETENCRYPT"
+                    | "HCISETPTYPE"
+                    | "HCISETLINKPOL"
+                    | "HCISETLINKMODE"
+                    | "HCISETACLMTU"
+                    | "HCISETSCOMTU"
+                    | "HCIBLOCKADDR"
+                    | "HCIUNBLOCKADDR"
+                    | "HCIINQUIRY"
+                    | "L2CAP_OPTIONS"
+                    | "L2CAP_CONNINFO"
+                    | "L2CAP_LM"
+                    | "L2CAP_LM_MASTER"
+                    | "L2CAP_LM_AUTH"
+                    | "L2CAP_LM_ENCRYPT"
+                    | "L2CAP_LM_TRUSTED"
+                    | "L2CAP_LM_RELIABLE"
+                    | "L2CAP_LM_SECURE"
+                    | "RFCOMM_CONNINFO"
+                    | "RFCOMM_LM"
+                    | "RFCOMM_LM_MASTER"
+                    | "RFCOMM_LM_AUTH"
+                    | "RFCOMM_LM_ENCRYPT"
+                    | "RFCOMM_LM_TRUSTED"
+                    | "RFCOMM_LM_RELIABLE"
+                    | "RFCOMM_LM_SECURE"
+                    | "SCO_OPTIONS"
+                    | "SCO_CONNINFO"
+            )
+        {
+            return true;
+        }
+
         if !gnu {
             // Skip definitions from the kernel on non-glibc Linux targets.
             // They're libc-independent, so we only need to check them on one
@@ -4770,6 +5003,9 @@ fn test_linux(t: &Target) {
             // because including `linux/if_arp.h` causes some conflicts:
             "ARPHRD_CAN" => true,
 
+            // ctest can't represent string literal constants
+            "ETHTOOL_GENL_NAME" | "ETHTOOL_MCGRP_MONITOR_NAME" => true,
+
             // FIXME(linux): conflicts with glibc headers and is tested in
             // `linux_termios.rs` below:
             "BOTHER" | "IBSHIFT" | "TCGETS2" | "TCSETS2" | "TCSETSW2" | "TCSETSF2" => true,
@@ -4878,6 +5114,58 @@ fn test_linux(t: &Target) {
             }
 
             "XDP_UMEM_TX_METADATA_LEN" => kernel < (6, 11),
+            "XDP_TXMD_FLAGS_LAUNCH_TIME" => musl || kernel < (6, 15),
+            // Added in kernel 6.6
+            "PROC_EVENT_NONZERO_EXIT" | "PROC_EVENT_ALL" => kernel < (6, 6),
+            "MPTCP_FULL_INFO" => kernel < (6, 5),
+            "SCHED_EXT" => kernel < (6, 12),
+            // FIXME(musl): RELR support was added in glibc 2.36 and musl 1.2.4
+            "SHT_RELR" | "DT_RELRSZ" | "DT_RELR" | "DT_RELRENT" => {
+                musl || (gnu && versions.glibc.unwrap() < (2, 36))
+            }
+            // AArch64 signal frame records added in kernel 5.19 and 6.3, not in musl's headers
+            "ZA_MAGIC" | "ZA_SIG_REGS_OFFSET" | "SVE_SIG_FLAG_SM" => musl || kernel < (5, 19),
+            "TPIDR2_MAGIC" | "ZT_MAGIC" => musl || kernel < (6, 3),
+            n if n.starts_with("ZT_SIG_") => musl || kernel < (6, 3),
+            // Added in glibc 2.35 and 2.36
+            "DLFO_EH_SEGMENT_TYPE" | "LAV_CURRENT" if gnu && versions.glibc.unwrap() < (2, 35) => {
+                true
+            }
+            "RTLD_DI_PHDR" | "RTLD_DI_MAX" if gnu && versions.glibc.unwrap() < (2, 36) => true,
+            "POSIX_SPAWN_SETCGROUP" if gnu && versions.glibc.unwrap() < (2, 39) => true,
+            // FIXME(musl): these GNU extensions aren't in musl's `elf.h` yet
+            "SHF_GNU_RETAIN" | "DF_1_KMOD" | "DF_1_WEAKFILTER" | "DF_1_NOCOMMON" if musl => true,
+            n if musl && n.starts_with("GNU_PROPERTY_") => true,
+            // The I/O priority level and hint definitions were added in kernel 6.5
+            "IOPRIO_NR_CLASSES"
+            | "IOPRIO_CLASS_INVALID"
+            | "IOPRIO_LEVEL_NR_BITS"
+            | "IOPRIO_LEVEL_MASK"
+            | "IOPRIO_NR_HINTS" => kernel < (6, 5),
+            n if n.starts_with("IOPRIO_HINT_") => kernel < (6, 5),
+            "CAN_ISOTP_SF_BROADCAST" => kernel < (5, 11),
+            "CAN_ISOTP_CF_BROADCAST" => kernel < (6, 0),
+            "CAN_ISOTP_DYN_FC_PARMS" => kernel < (6, 6),
+            // CAN XL VCID support was added in kernel 6.9
+            "CAN_RAW_XL_VCID_OPTS"
+            | "CAN_RAW_XL_VCID_TX_SET"
+            | "CAN_RAW_XL_VCID_TX_PASS"
+            | "CAN_RAW_XL_VCID_RX_FILTER"
+            | "CANXL_VCID_OFFSET"
+            | "CANXL_VCID_VAL_MASK"
+            | "CANXL_VCID_MASK" => kernel < (6, 9),
+            // Only in `<linux/tcp.h>`, which can't be included alongside `netinet/tcp.h`. These
+            // are tested in the `linux_tcp.rs` file.
+            "TCP_AO_ADD_KEY"
+            | "TCP_AO_DEL_KEY"
+            | "TCP_AO_INFO"
+            | "TCP_AO_GET_KEYS"
+            | "TCP_AO_REPAIR"
+            | "TCP_AO_MAXKEYLEN"
+            | "TCP_AO_KEYF_IFINDEX"
+            | "TCP_AO_KEYF_EXCLUDE_OPT"
+            | "TCP_MD5SIG_FLAG_IFINDEX"
+            | "TCP_RECEIVE_ZEROCOPY_FLAG_TLB_CLEAN_HINT" => true,
             "NS_GET_MNTNS_ID"
             | "NS_GET_PID_FROM_PIDNS"
             | "NS_GET_TGID_FROM_PIDNS"
@@ -4907,6 +5195,9 @@ fn test_linux(t: &Target) {
             "EPIOCSPARAMS" | "EPIOCGPARAMS" => kernel < (6, 9),
             "MAP_DROPPABLE" => kernel < (6, 11),
             "SOF_TIMESTAMPING_OPT_RX_FILTER" => kernel < (6, 12),
+            "SOF_TIMESTAMPING_TX_COMPLETION"
+            | "SOF_TIMESTAMPING_TX_RECORD_MASK"
+            | "SCM_TSTAMP_COMPLETION" => kernel < (6, 14),
 
             "SO_DEVMEM_LINEAR" | "SO_DEVMEM_DMABUF" | "SO_DEVMEM_DONTNEED"
             | "SCM_DEVMEM_LINEAR" | "SCM_DEVMEM_DMABUF" => kernel < (6, 12),
@@ -4923,8 +5214,110 @@ fn test_linux(t: &Target) {
             // Recent additions
             "AT_HWCAP3" | "AT_HWCAP4" if old_musl => true,
             "AT_HWCAP3" | "AT_HWCAP4" => kernel < (6, 9),
+            "AT_RSEQ_FEATURE_SIZE" | "AT_RSEQ_ALIGN" if gnu => {
+                kernel < (6, 3) && versions.glibc.unwrap() < (2, 35)
+            }
+            "AT_RSEQ_FEATURE_SIZE" | "AT_RSEQ_ALIGN" => kernel < (6, 3),
+
+            // Per-architecture hwcaps
+            "HWCAP2_CSSC" | "HWCAP2_RPRFM" | "HWCAP2_SVE2P1" | "HWCAP2_SME2" | "HWCAP2_SME2P1"
+            | "HWCAP2_SME_I16I32" | "HWCAP2_SME_BI32I32" | "HWCAP2_SME_B16B16"
+            | "HWCAP2_SME_F16F16" => kernel < (6, 3),
+            "HWCAP2_MOPS" | "HWCAP2_HBC" => kernel < (6, 5),
+            "HWCAP2_SVE_B16B16" | "HWCAP2_LRCPC3" | "HWCAP2_LSE128" => kernel < (6, 7),
+            "HWCAP2_FPMR" | "HWCAP2_LUT" | "HWCAP2_FAMINMAX" | "HWCAP2_F8CVT" | "HWCAP2_F8FMA"
+            | "HWCAP2_F8DP4" | "HWCAP2_F8DP2" | "HWCAP2_F8E4M3" | "HWCAP2_F8E5M2"
+            | "HWCAP2_SME_LUTV2" | "HWCAP2_SME_F8F16" | "HWCAP2_SME_F8F32"
+            | "HWCAP2_SME_SF8FMA" | "HWCAP2_SME_SF8DP4" | "HWCAP2_SME_SF8DP2" => kernel < (6, 10),
+            "HWCAP2_POE" => kernel < (6, 12),
+            "HWCAP_GCS" => kernel < (6, 13),
+            "HWCAP_CMPBR" | "HWCAP_FPRCVT" | "HWCAP_F8MM8" | "HWCAP_F8MM4" | "HWCAP_SVE_F16MM"
+            | "HWCAP_SVE_ELTPERM" | "HWCAP_SVE_AES2" | "HWCAP_SVE_BFSCALE" | "HWCAP_SVE2P2"
+            | "HWCAP_SME2P2" | "HWCAP_SME_SBITPERM" | "HWCAP_SME_AES" | "HWCAP_SME_SFEXPA"
+            | "HWCAP_SME_STMOP" | "HWCAP_SME_SMOP4" => kernel < (6, 16),
+            "HWCAP3_MTE_FAR" | "HWCAP3_MTE_STORE_ONLY" => kernel < (6, 18),
+            "HWCAP_ASIMDBF16" | "HWCAP_I8MM" | "HWCAP2_SB" | "HWCAP2_SSBS" if arm32 => {
+                kernel < (6, 1)
+            }
+            "HWCAP_LOONGARCH_LSPW" => kernel < (6, 12),
+
+            // riscv_hwprobe keys and values
+            "RISCV_HWPROBE_EXT_ZBA" | "RISCV_HWPROBE_EXT_ZBB" | "RISCV_HWPROBE_EXT_ZBS" => {
+                kernel < (6, 5)
+            }
+            "RISCV_HWPROBE_EXT_ZICBOZ" | "RISCV_HWPROBE_KEY_ZICBOZ_BLOCK_SIZE" => kernel < (6, 6),
+            "RISCV_HWPROBE_EXT_ZBC"
+            | "RISCV_HWPROBE_EXT_ZBKB"
+            | "RISCV_HWPROBE_EXT_ZBKC"
+            | "RISCV_HWPROBE_EXT_ZBKX"
+            | "RISCV_HWPROBE_EXT_ZKND"
+            | "RISCV_HWPROBE_EXT_ZKNE"
+            | "RISCV_HWPROBE_EXT_ZKNH"
+            | "RISCV_HWPROBE_EXT_ZKSED"
+            | "RISCV_HWPROBE_EXT_ZKSH"
+            | "RISCV_HWPROBE_EXT_ZKT"
+            | "RISCV_HWPROBE_EXT_ZVBB"
+            | "RISCV_HWPROBE_EXT_ZVBC"
+            | "RISCV_HWPROBE_EXT_ZVKB"
+            | "RISCV_HWPROBE_EXT_ZVKG"
+            | "RISCV_HWPROBE_EXT_ZVKNED"
+            | "RISCV_HWPROBE_EXT_ZVKNHA"
+            | "RISCV_HWPROBE_EXT_ZVKNHB"
+            | "RISCV_HWPROBE_EXT_ZVKSED"
+            | "RISCV_HWPROBE_EXT_ZVKSH"
+            | "RISCV_HWPROBE_EXT_ZVKT"
+            | "RISCV_HWPROBE_EXT_ZFH"
+            | "RISCV_HWPROBE_EXT_ZFHMIN"
+            | "RISCV_HWPROBE_EXT_ZIHINTNTL"
+            | "RISCV_HWPROBE_EXT_ZVFH"
+            | "RISCV_HWPROBE_EXT_ZVFHMIN"
+            | "RISCV_HWPROBE_EXT_ZFA"
+            | "RISCV_HWPROBE_EXT_ZTSO"
+            | "RISCV_HWPROBE_EXT_ZACAS"
+            | "RISCV_HWPROBE_EXT_ZICOND"
+            | "RISCV_HWPROBE_KEY_HIGHEST_VIRT_ADDRESS" => kernel < (6, 8),
+            "RISCV_HWPROBE_WHICH_CPUS" | "RISCV_HWPROBE_KEY_TIME_CSR_FREQ" => kernel < (6, 9),
+            "RISCV_HWPROBE_EXT_ZIHINTPAUSE" => kernel < (6, 10),
+            "RISCV_HWPROBE_EXT_ZVE32X"
+            | "RISCV_HWPROBE_EXT_ZVE32F"
+            | "RISCV_HWPROBE_EXT_ZVE64X"
+            | "RISCV_HWPROBE_EXT_ZVE64F"
+            | "RISCV_HWPROBE_EXT_ZVE64D"
+            | "RISCV_HWPROBE_EXT_ZIMOP"
+            | "RISCV_HWPROBE_EXT_ZCA"
+            | "RISCV_HWPROBE_EXT_ZCB"
+            | "RISCV_HWPROBE_EXT_ZCD"
+            | "RISCV_HWPROBE_EXT_ZCF"
+            | "RISCV_HWPROBE_EXT_ZCMOP"
+            | "RISCV_HWPROBE_EXT_ZAWRS"
+            | "RISCV_HWPROBE_MISALIGNED_UNSUPPORTED"
+            | "RISCV_HWPROBE_MISALIGNED_MASK"
+            | "RISCV_HWPROBE_KEY_MISALIGNED_SCALAR_PERF"
+            | "RISCV_HWPROBE_MISALIGNED_SCALAR_UNKNOWN"
+            | "RISCV_HWPROBE_MISALIGNED_SCALAR_EMULATED"
+            | "RISCV_HWPROBE_MISALIGNED_SCALAR_SLOW"
+            | "RISCV_HWPROBE_MISALIGNED_SCALAR_FAST"
+            | "RISCV_HWPROBE_MISALIGNED_SCALAR_UNSUPPORTED" => kernel < (6, 11),
+            "RISCV_HWPROBE_KEY_MISALIGNED_VECTOR_PERF"
+            | "RISCV_HWPROBE_MISALIGNED_VECTOR_UNKNOWN"
+            | "RISCV_HWPROBE_MISALIGNED_VECTOR_SLOW"
+            | "RISCV_HWPROBE_MISALIGNED_VECTOR_FAST"
+            | "RISCV_HWPROBE_MISALIGNED_VECTOR_UNSUPPORTED" => kernel < (6, 12),
+            "RISCV_HWPROBE_EXT_SUPM" => kernel < (6, 13),
+            "RISCV_HWPROBE_EXT_ZICNTR"
+            | "RISCV_HWPROBE_EXT_ZIHPM"
+            | "RISCV_HWPROBE_EXT_ZFBFMIN"
+            | "RISCV_HWPROBE_EXT_ZVFBFMIN"
+            | "RISCV_HWPROBE_EXT_ZVFBFWMA"
+            | "RISCV_HWPROBE_EXT_ZICBOM"
+            | "RISCV_HWPROBE_EXT_ZAAMO"
+            | "RISCV_HWPROBE_EXT_ZALRSC"
+            | "RISCV_HWPROBE_EXT_ZABHA"
+            | "RISCV_HWPROBE_KEY_ZICBOM_BLOCK_SIZE" => kernel < (6, 15),
+            n if n.starts_with("RISCV_HWPROBE_") => kernel < (6, 4),
             "PTRACE_SET_SYSCALL_INFO" => kernel < (6, 16),
             "TLS_INFO_TX_MAX_PAYLOAD_LEN" | "TLS_INFO_MAX" => kernel < (6, 19),
+            "VIRTIO_NET_HDR_GSO_UDP_L4" => kernel < (6, 2),
 
             // statx mask and attribute bits
             "STATX_MNT_ID_UNIQUE" => kernel < (6, 8),
@@ -4953,6 +5346,14 @@ fn test_linux(t: &Target) {
         }
     });
 
+    cfg.skip_static(move |static_| match static_.ident() {
+        // Added in glibc 2.35
+        "__rseq_offset" | "__rseq_size" | "__rseq_flags" => {
+            gnu && versions.glibc.unwrap() < (2, 35)
+        }
+        _ => false,
+    });
+
     let c_enums = [
         "can_state",
         "fsconfig_command",
@@ -4960,6 +5361,10 @@ fn test_linux(t: &Target) {
         "pid_type",
         "proc_cn_event",
         "proc_cn_mcast_op",
+        "rseq_cpu_id_state",
+        "rseq_cs_flags",
+        "rseq_cs_flags_bit",
+        "rseq_flags",
         "tpacket_versions",
     ];
     cfg.alias_is_c_enum(move |e| c_enums.contains(&e));
@@ -5005,8 +5410,28 @@ fn test_linux(t: &Target) {
             "posix_spawn_file_actions_addclosefrom_np" if gnu && sparc64 => true,
             // Needs glibc 2.35 or later.
             "posix_spawn_file_actions_addtcsetpgrp_np" if gnu && sparc64 => true,
+            // Needs glibc 2.39 or later.
+            "pidfd_spawn"
+            | "pidfd_spawnp"
+            | "posix_spawnattr_getcgroup_np"
+            | "posix_spawnattr_setcgroup_np"
+                if gnu && versions.glibc.unwrap() < (2, 39) =>
+            {
+                true
+            }
             // Needs glibc 2.42 or later.
-            "pthread_gettid_np" if gnu && versions.glibc.unwrap() < (2, 42) => true,
+            "pthread_gettid_np"
+            | "posix_spawn_file_actions_addchdir"
+            | "posix_spawn_file_actions_addfchdir"
+                if gnu && versions.glibc.unwrap() < (2, 42) =>
+            {
+                true
+            }
+
+            // Added in glibc 2.35
+            "_dl_find_object" if gnu && versions.glibc.unwrap() < (2, 35) => true,
+            // Added in glibc 2.40
+            "__riscv_hwprobe" if gnu && versions.glibc.unwrap() < (2, 40) => true,
 
             // FIXME(linux): Deprecated since glibc 2.30. Remove fn once upstream does.
             "sysctl" if gnu => true,
@@ -5103,6 +5528,29 @@ fn test_linux(t: &Target) {
             ("ptp_perout_request", "anonymous_1") => true,
             // `anonymous_2` is an anonymous union
             ("ptp_perout_request", "anonymous_2") => true,
+            // `anonymous_1` is an anonymous union
+            ("nvme_passthru_cmd64", "anonymous_1") => true,
+            // `anonymous_1` is an anonymous union
+            ("rtc_param", "anonymous_1") => true,
+            // `anonymous_1` is an anonymous union
+            ("virtio_net_hdr_v1", "anonymous_1") => true,
+            // the `xsk_tx_metadata_union` field is an anonymous union
+            ("xsk_tx_metadata", "xsk_tx_metadata_union") => true,
+            // `r`, `e` and `event_data` are anonymous unions
+            ("id_proc_event", "r" | "e") => true,
+            ("proc_event", "event_data") => true,
+            // `anonymous_1` and `anonymous_2` are anonymous unions
+            ("mptcp_subflow_addrs", "anonymous_1" | "anonymous_2") => true,
+            // `what` was an anonymous `enum what` before kernel 6.6
+            ("proc_event", "what") => kernel < (6, 6),
+            // `d_un` and `a_un` are anonymous unions
+            ("Elf32_Dyn" | "Elf64_Dyn", "d_un") => true,
+            ("Elf32_auxv_t" | "Elf64_auxv_t", "a_un") => true,
+            // `r_state` is an anonymous enum
+            ("r_debug", "r_state") => true,
+            // `long double`
+            ("La_x86_64_retval" | "La_i86_retval", "lrv_st0" | "lrv_st1") => true,
+            ("La_aarch64_vector", "q") => true,
             // FIXME(linux): `adjust_phase` requires >= 5.7 kernel headers
             // FIXME(linux): `max_phase_adj` requires >= 5.19 kernel headers
             // the rsv field shrunk when those fields got added, so is omitted too
@@ -5124,6 +5572,8 @@ fn test_linux(t: &Target) {
             ("siginfo_t", "_pad") => true,
             // musl names this __dummy1 but it's still there
             ("glob_t", "gl_flags") if musl => true,
+            // `dls_serpath` is a zero-length array in an anonymous union
+            ("Dl_serinfo", "dls_serpath") => true,
             // musl seems to define this as an *anonymous* bitfield
             ("statvfs", "__f_unused") if musl => true,
             // _sigev_un is an anonymous union
@@ -5186,6 +5636,12 @@ fn test_linux(t: &Target) {
             ("ptp_perout_request", "anonymous_1") => true,
             // `anonymous_2` is an anonymous union
             ("ptp_perout_request", "anonymous_2") => true,
+            // `anonymous_1` is an anonymous union
+            ("nvme_passthru_cmd64", "anonymous_1") => true,
+            // `anonymous_1` is an anonymous union
+            ("rtc_param", "anonymous_1") => true,
+            // `anonymous_1` is an anonymous union
+            ("virtio_net_hdr_v1", "anonymous_1") => true,
             // FIXME(linux): `adjust_phase` requires >= 5.7 kernel headers
             // FIXME(linux): `max_phase_adj` requires >= 5.19 kernel headers
             // the rsv field shrunk when those fields got added, so is omitted too
@@ -5201,10 +5657,24 @@ fn test_linux(t: &Target) {
             ("statvfs64", "__f_spare") => true,
             // the `xsk_tx_metadata_union` field is an anonymous union
             ("xsk_tx_metadata", "xsk_tx_metadata_union") => true,
+            // `r`, `e` and `event_data` are anonymous unions
+            ("id_proc_event", "r" | "e") => true,
+            ("proc_event", "event_data") => true,
+            // `anonymous_1` and `anonymous_2` are anonymous unions
+            ("mptcp_subflow_addrs", "anonymous_1" | "anonymous_2") => true,
             // After musl 1.2.0, the type becomes `int` instead of `long`.
             ("utmpx", "ut_session") if musl => true,
             // `frames` is a flexible array member
             ("bcm_msg_head", "frames") => true,
+            // `addr` is a flexible array member
+            ("tun_filter", "addr") => true,
+            // `data` is a flexible array member
+            ("cn_msg", "data") => true,
+            // glibc and musl name this field `__tcpm_pad`
+            ("tcp_md5sig", "tcpm_ifindex") => true,
+            // `data` and `link_mode_masks` are flexible array members
+            ("ethtool_gstrings" | "ethtool_sset_info" | "ethtool_stats", "data") => true,
+            ("ethtool_link_settings", "link_mode_masks") => true,
             // FAM
             ("af_alg_iv", "iv") => true,
             ("file_handle", "f_handle") if musl || uclibc => true,
@@ -5236,6 +5706,12 @@ fn test_linux(t: &Target) {
         "fanotify_event_info_fid" => true,
         "cmsghdr" => true,
         "bcm_msg_head" => true,
+        "tun_filter" => true,
+        "cn_msg" => true,
+        "ethtool_gstrings" => true,
+        "ethtool_sset_info" => true,
+        "ethtool_stats" => true,
+        "ethtool_link_settings" => true,
 
         // FIXME(linux): the call ABI of max_align_t is incorrect on these platforms:
         "max_align_t" if x86_32 => true,
@@ -5428,6 +5904,71 @@ fn test_linux_like_apis(t: &Target) {
 
         ctest::generate_test(&mut cfg, "../src/lib.rs", "linux_if_arp.rs").unwrap();
     }
+
+    if linux && !wali {
+        // Test the items that are only in `<linux/tcp.h>`, which can't be included alongside
+        // `<netinet/tcp.h>`.
+        let kernel = VERSIONS.linux.unwrap();
+        let tcp_structs = [
+            "tcp_diag_md5sig",
+            "tcp_zerocopy_receive",
+            "tcp_ao_add",
+            "tcp_ao_info_opt",
+        ];
+        let mut cfg = ctest_cfg();
+        config_gnu_bits(t, &mut cfg);
+        cfg.header("linux/tcp.h")
+            .skip_fn(|_| true)
+            .skip_static(|_| true)
+            .skip_union(|_| true)
+            .skip_alias(|_| true)
+            .skip_struct(move |struct_| match struct_.ident() {
+                "tcp_ao_add" | "tcp_ao_info_opt" => kernel < (6, 7),
+                s => !tcp_structs.contains(&s),
+            })
+            .skip_const(move |constant| match constant.ident() {
+                "TCP_AO_ADD_KEY"
+                | "TCP_AO_DEL_KEY"
+                | "TCP_AO_INFO"
+                | "TCP_AO_GET_KEYS"
+                | "TCP_AO_REPAIR"
+                | "TCP_AO_MAXKEYLEN"
+                | "TCP_AO_KEYF_IFINDEX"
+                | "TCP_AO_KEYF_EXCLUDE_OPT" => kernel < (6, 7),
+                "TCP_MD5SIG_FLAG_IFINDEX" | "TCP_RECEIVE_ZEROCOPY_FLAG_TLB_CLEAN_HINT" => false,
+                _ => true,
+            })
+            // The bitfields are exposed as a single `bitfields` field.
+            .skip_struct_field(|struct_, field| {
+                matches!(
+                    (struct_.ident(), field.ident()),
+                    ("tcp_ao_add" | "tcp_ao_info_opt", "bitfields")
+                )
+            })
+            .rename_struct_ty(|ty| {
+                (ty == "sockaddr_storage").then_some("struct __kernel_sockaddr_storage".to_string())
+            });
+
+        ctest::generate_test(&mut cfg, "../src/lib.rs", "linux_tcp.rs").unwrap();
+    }
+
+    if linux && !wali {
+        // Test `sched_attr` against `<linux/sched/types.h>` on its own, as the header conflicts
+        // with `<sched.h>` on older kernels.
+        let kernel = VERSIONS.linux.unwrap();
+        let mut cfg = ctest_cfg();
+        config_gnu_bits(t, &mut cfg);
+        cfg.header("linux/sched/types.h")
+            .skip_fn(|_| true)
+            .skip_static(|_| true)
+            .skip_const(|_| true)
+            .skip_union(|_| true)
+            .skip_alias(|_| true)
+            // `sched_util_min` and `sched_util_max` were added in kernel 5.3
+            .skip_struct(move |struct_| struct_.ident() != "sched_attr" || kernel < (5, 3));
+
+        ctest::generate_test(&mut cfg, "../src/lib.rs", "linux_sched_types.rs").unwrap();
+    }
 }
 
 fn test_haiku(t: &Target) {
@@ -0,0 +1,54 @@
+#define _GNU_SOURCE
+#include <sched.h>
+
+// The CPU_* family are macros instead of functions, so they aren't available
+// to FFI. libc must reimplement them; this file provides FFI access to the
+// actual macros so they can be tested against the Rust reimplementation.
+
+size_t cpu_alloc_size_ffi(int count) {
+    return CPU_ALLOC_SIZE(count);
+}
+
+cpu_set_t *cpu_alloc_ffi(int count) {
+    return CPU_ALLOC(count);
+}
+
+void cpu_free_ffi(cpu_set_t *set) {
+    CPU_FREE(set);
+}
+
+void cpu_zero_s_ffi(size_t size, cpu_set_t *set) {
+    CPU_ZERO_S(size, set);
+}
+
+void cpu_set_s_ffi(size_t cpu, size_t size, cpu_set_t *set) {
+    CPU_SET_S(cpu, size, set);
+}
+
+void cpu_clr_s_ffi(size_t cpu, size_t size, cpu_set_t *set) {
+    CPU_CLR_S(cpu, size, set);
+}
+
+int cpu_isset_s_ffi(size_t cpu, size_t size, const cpu_set_t *set) {
+    return CPU_ISSET_S(cpu, size, set);
+}
+
+int cpu_count_s_ffi(size_t size, const cpu_set_t *set) {
+    return CPU_COUNT_S(size, set);
+}
+
+int cpu_equal_s_ffi(size_t size, const cpu_set_t *set1, const cpu_set_t *set2) {
+    return CPU_EQUAL_S(size, set1, set2);
+}
+
+void cpu_and_s_ffi(size_t size, cpu_set_t *dest, const cpu_set_t *src1, const cpu_set_t *src2) {
+    CPU_AND_S(size, dest, src1, src2);
+}
+
+void cpu_or_s_ffi(size_t size, cpu_set_t *dest, const cpu_set_t *src1, const cpu_set_t *src2) {
+    CPU_OR_S(size, dest, src1, src2);
+}
+
+void cpu_xor_s_ffi(size_t size, cpu_set_t *dest, const cpu_set_t *src1, const cpu_set_t *src2) {
+    CPU_XOR_S(size, dest, src1, src2);
+}
@@ -0,0 +1,30 @@
+#include <elf.h>
+#include <stdint.h>
+
+// The ELF32_R_* and ELF64_R_* helpers are macros, so they aren't available to FFI. This file
+// provides FFI access to the actual macros so they can be tested against the Rust
+// reimplementation.
+
+Elf32_Word elf32_r_info_ffi(Elf32_Word sym, Elf32_Word type) {
+    return ELF32_R_INFO(sym, type);
+}
+
+Elf32_Word elf32_r_sym_ffi(Elf32_Word info) {
+    return ELF32_R_SYM(info);
+}
+
+Elf32_Word elf32_r_type_ffi(Elf32_Word info) {
+    return ELF32_R_TYPE(info);
+}
+
+Elf64_Xword elf64_r_info_ffi(Elf64_Xword sym, Elf64_Xword type) {
+    return ELF64_R_INFO(sym, type);
+}
+
+Elf64_Xword elf64_r_sym_ffi(Elf64_Xword info) {
+    return ELF64_R_SYM(info);
+}
+
+Elf64_Xword elf64_r_type_ffi(Elf64_Xword info) {
+    return ELF64_R_TYPE(info);
+}
@@ -0,0 +1,13 @@
+#include <errno.h>
+
+// `errno` is a macro expanding to a thread-local lvalue, so it isn't available
+// to FFI. These give access to the C view of it so that libc's `errno` and
+// `set_errno` can be tested against it.
+
+int errno_ffi(void) {
+    return errno;
+}
+
+void set_errno_ffi(int value) {
+    errno = value;
+}
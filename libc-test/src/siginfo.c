@@ -0,0 +1,114 @@
+#include <signal.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <string.h>
+
+// The siginfo_t fields are macros expanding to members of the `_sifields`
+// union, so they aren't available to FFI. libc provides accessor methods
+// instead; this file provides FFI access to the actual macros so they can be
+// tested against the Rust reimplementation.
+
+pid_t si_pid_ffi(const siginfo_t *si) {
+    return si->si_pid;
+}
+
+uid_t si_uid_ffi(const siginfo_t *si) {
+    return si->si_uid;
+}
+
+int si_timerid_ffi(const siginfo_t *si) {
+    return si->si_timerid;
+}
+
+int si_overrun_ffi(const siginfo_t *si) {
+    return si->si_overrun;
+}
+
+void *si_value_ffi(const siginfo_t *si) {
+    return si->si_value.sival_ptr;
+}
+
+int si_status_ffi(const siginfo_t *si) {
+    return si->si_status;
+}
+
+long si_utime_ffi(const siginfo_t *si) {
+    return si->si_utime;
+}
+
+long si_stime_ffi(const siginfo_t *si) {
+    return si->si_stime;
+}
+
+void *si_addr_ffi(const siginfo_t *si) {
+    return si->si_addr;
+}
+
+short si_addr_lsb_ffi(const siginfo_t *si) {
+    return si->si_addr_lsb;
+}
+
+void *si_lower_ffi(const siginfo_t *si) {
+    return si->si_lower;
+}
+
+void *si_upper_ffi(const siginfo_t *si) {
+    return si->si_upper;
+}
+
+uint32_t si_pkey_ffi(const siginfo_t *si) {
+    return si->si_pkey;
+}
+
+#ifdef si_perf_data
+unsigned long si_perf_data_ffi(const siginfo_t *si) {
+    return si->si_perf_data;
+}
+
+uint32_t si_perf_type_ffi(const siginfo_t *si) {
+    return si->si_perf_type;
+}
+
+uint32_t si_perf_flags_ffi(const siginfo_t *si) {
+    return si->si_perf_flags;
+}
+#else
+// glibc and musl don't name the TRAP_PERF fields. The kernel places them in
+// the same union as `si_addr_lsb` and the bounds, with the type and flags
+// overlapping `si_pkey`.
+unsigned long si_perf_data_ffi(const siginfo_t *si) {
+    unsigned long data;
+    memcpy(&data, (const char *)si + offsetof(siginfo_t, si_addr_lsb), sizeof(data));
+    return data;
+}
+
+uint32_t si_perf_type_ffi(const siginfo_t *si) {
+    return si->si_pkey;
+}
+
+uint32_t si_perf_flags_ffi(const siginfo_t *si) {
+    uint32_t flags;
+    memcpy(&flags, (const char *)si + offsetof(siginfo_t, si_pkey) + sizeof(flags), sizeof(flags));
+    return flags;
+}
+#endif
+
+long si_band_ffi(const siginfo_t *si) {
+    return si->si_band;
+}
+
+int si_fd_ffi(const siginfo_t *si) {
+    return si->si_fd;
+}
+
+void *si_call_addr_ffi(const siginfo_t *si) {
+    return si->si_call_addr;
+}
+
+int si_syscall_ffi(const siginfo_t *si) {
+    return si->si_syscall;
+}
+
+unsigned int si_arch_ffi(const siginfo_t *si) {
+    return si->si_arch;
+}
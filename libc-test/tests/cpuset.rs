@@ -0,0 +1,176 @@
+//! Compare libc's CPU_* functions for dynamically sized CPU sets against the actual C macros.
+
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use libc::{
+    c_int,
+    cpu_set_t,
+    size_t,
+};
+
+extern "C" {
+    fn cpu_alloc_size_ffi(count: c_int) -> size_t;
+    fn cpu_alloc_ffi(count: c_int) -> *mut cpu_set_t;
+    fn cpu_free_ffi(set: *mut cpu_set_t);
+    fn cpu_zero_s_ffi(size: size_t, set: *mut cpu_set_t);
+    fn cpu_set_s_ffi(cpu: size_t, size: size_t, set: *mut cpu_set_t);
+    fn cpu_clr_s_ffi(cpu: size_t, size: size_t, set: *mut cpu_set_t);
+    fn cpu_isset_s_ffi(cpu: size_t, size: size_t, set: *const cpu_set_t) -> c_int;
+    fn cpu_count_s_ffi(size: size_t, set: *const cpu_set_t) -> c_int;
+    fn cpu_equal_s_ffi(size: size_t, set1: *const cpu_set_t, set2: *const cpu_set_t) -> c_int;
+    fn cpu_and_s_ffi(
+        size: size_t,
+        dest: *mut cpu_set_t,
+        src1: *const cpu_set_t,
+        src2: *const cpu_set_t,
+    );
+    fn cpu_or_s_ffi(
+        size: size_t,
+        dest: *mut cpu_set_t,
+        src1: *const cpu_set_t,
+        src2: *const cpu_set_t,
+    );
+    fn cpu_xor_s_ffi(
+        size: size_t,
+        dest: *mut cpu_set_t,
+        src1: *const cpu_set_t,
+        src2: *const cpu_set_t,
+    );
+}
+
+// Larger than `CPU_SETSIZE` on every platform.
+const COUNT: c_int = 4100;
+
+#[test]
+fn test_alloc_size() {
+    for count in (0..=COUNT).chain([65, 127, 128, 129]) {
+        assert_eq!(
+            unsafe { libc::CPU_ALLOC_SIZE(count) },
+            unsafe { cpu_alloc_size_ffi(count) },
+            "count: {count}"
+        );
+    }
+}
+
+#[test]
+fn test_set_clr_isset_count() {
+    unsafe {
+        let size = libc::CPU_ALLOC_SIZE(COUNT);
+        let rust = libc::CPU_ALLOC(COUNT);
+        let c = cpu_alloc_ffi(COUNT);
+        assert!(!rust.is_null() && !c.is_null());
+
+        libc::CPU_ZERO_S(size, rust);
+        cpu_zero_s_ffi(size, c);
+        assert_eq!(libc::CPU_COUNT_S(size, rust), 0);
+
+        let cpus = (0..COUNT as usize).step_by(7).chain([1023, 1024, 4095]);
+        for cpu in cpus.clone() {
+            libc::CPU_SET_S(cpu, size, rust);
+            cpu_set_s_ffi(cpu, size, c);
+        }
+        // Out of range CPUs are ignored.
+        libc::CPU_SET_S(size * 8, size, rust);
+        cpu_set_s_ffi(size * 8, size, c);
+
+        assert!(libc::CPU_EQUAL_S(size, rust, c));
+        assert_eq!(libc::CPU_COUNT_S(size, rust), cpu_count_s_ffi(size, c));
+        for cpu in 0..size * 8 + 64 {
+            assert_eq!(
+                libc::CPU_ISSET_S(cpu, size, rust),
+                cpu_isset_s_ffi(cpu, size, c) != 0,
+                "cpu: {cpu}"
+            );
+        }
+
+        for cpu in cpus.step_by(3) {
+            libc::CPU_CLR_S(cpu, size, rust);
+            cpu_clr_s_ffi(cpu, size, c);
+        }
+        assert!(libc::CPU_EQUAL_S(size, rust, c));
+        assert_eq!(libc::CPU_COUNT_S(size, rust), cpu_count_s_ffi(size, c));
+
+        libc::CPU_CLR_S(0, size, c);
+        libc::CPU_SET_S(4096, size, c);
+        assert_eq!(
+            libc::CPU_EQUAL_S(size, rust, c),
+            cpu_equal_s_ffi(size, rust, c) != 0
+        );
+
+        libc::CPU_FREE(rust);
+        cpu_free_ffi(c);
+    }
+}
+
+type Op = unsafe extern "C" fn(size_t, *mut cpu_set_t, *const cpu_set_t, *const cpu_set_t);
+
+#[test]
+fn test_logic_ops() {
+    let ops: [(Op, Op); 3] = [
+        (libc::CPU_AND_S, cpu_and_s_ffi),
+        (libc::CPU_OR_S, cpu_or_s_ffi),
+        (libc::CPU_XOR_S, cpu_xor_s_ffi),
+    ];
+
+    unsafe {
+        let size = libc::CPU_ALLOC_SIZE(COUNT);
+        let src1 = libc::CPU_ALLOC(COUNT);
+        let src2 = libc::CPU_ALLOC(COUNT);
+        let rust = libc::CPU_ALLOC(COUNT);
+        let c = libc::CPU_ALLOC(COUNT);
+        libc::CPU_ZERO_S(size, src1);
+        libc::CPU_ZERO_S(size, src2);
+        for cpu in (0..COUNT as usize).step_by(3) {
+            libc::CPU_SET_S(cpu, size, src1);
+        }
+        for cpu in (0..COUNT as usize).step_by(5) {
+            libc::CPU_SET_S(cpu, size, src2);
+        }
+
+        for (rust_op, c_op) in ops {
+            rust_op(size, rust, src1, src2);
+            c_op(size, c, src1, src2);
+            assert!(libc::CPU_EQUAL_S(size, rust, c));
+        }
+
+        for set in [src1, src2, rust, c] {
+            libc::CPU_FREE(set);
+        }
+    }
+}
+
+#[test]
+fn test_fixed_size() {
+    unsafe {
+        let mut a: cpu_set_t = core::mem::zeroed();
+        let mut b: cpu_set_t = core::mem::zeroed();
+        let mut dest: cpu_set_t = core::mem::zeroed();
+        let size = core::mem::size_of::<cpu_set_t>();
+        libc::CPU_SET(1, &mut a);
+        libc::CPU_SET(2, &mut a);
+        libc::CPU_SET(2, &mut b);
+        libc::CPU_SET(3, &mut b);
+
+        assert_eq!(libc::CPU_COUNT_S(size, &a), 2);
+        assert_eq!(libc::CPU_COUNT_S(size, &a), cpu_count_s_ffi(size, &a));
+        assert_eq!(libc::CPU_COUNT(&b), libc::CPU_COUNT_S(size, &b));
+
+        let ops: [(
+            unsafe extern "C" fn(&mut cpu_set_t, &cpu_set_t, &cpu_set_t),
+            Op,
+        ); 3] = [
+            (libc::CPU_AND, cpu_and_s_ffi),
+            (libc::CPU_OR, cpu_or_s_ffi),
+            (libc::CPU_XOR, cpu_xor_s_ffi),
+        ];
+        for (rust_op, c_op) in ops {
+            let mut c: cpu_set_t = core::mem::zeroed();
+            rust_op(&mut dest, &a, &b);
+            c_op(size, &mut c, &a, &b);
+            assert!(libc::CPU_EQUAL(&dest, &c));
+        }
+        assert_eq!(libc::CPU_COUNT(&dest), 2);
+        assert!(libc::CPU_ISSET(1, &dest));
+        assert!(libc::CPU_ISSET(3, &dest));
+    }
+}
@@ -0,0 +1,60 @@
+//! Compare libc's ELF32_R_* and ELF64_R_* functions against the actual C macros.
+
+#![cfg(target_os = "linux")]
+
+use libc::{
+    Elf32_Word,
+    Elf64_Xword,
+};
+
+extern "C" {
+    fn elf32_r_info_ffi(sym: Elf32_Word, t: Elf32_Word) -> Elf32_Word;
+    fn elf32_r_sym_ffi(info: Elf32_Word) -> Elf32_Word;
+    fn elf32_r_type_ffi(info: Elf32_Word) -> Elf32_Word;
+    fn elf64_r_info_ffi(sym: Elf64_Xword, t: Elf64_Xword) -> Elf64_Xword;
+    fn elf64_r_sym_ffi(info: Elf64_Xword) -> Elf64_Xword;
+    fn elf64_r_type_ffi(info: Elf64_Xword) -> Elf64_Xword;
+}
+
+#[test]
+fn test_elf32_r_info() {
+    let syms = [0, 1, 0x1234, 0xff_ffff];
+    let types = [0, 1, 8, 0x7f, 0xff, 0x100, 0x1ff];
+    for sym in syms {
+        for t in types {
+            unsafe {
+                let info = libc::ELF32_R_INFO(sym, t);
+                assert_eq!(
+                    info,
+                    elf32_r_info_ffi(sym, t),
+                    "sym: {sym:#x}, type: {t:#x}"
+                );
+                assert_eq!(libc::ELF32_R_SYM(info), elf32_r_sym_ffi(info));
+                assert_eq!(libc::ELF32_R_TYPE(info), elf32_r_type_ffi(info));
+                assert_eq!(libc::ELF32_R_SYM(info), sym);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_elf64_r_info() {
+    let syms = [0, 1, 0x1234, 0xffff_ffff];
+    let types = [0, 1, 8, 0xff, 0x100, 0xffff_ffff];
+    for sym in syms {
+        for t in types {
+            unsafe {
+                let info = libc::ELF64_R_INFO(sym, t);
+                assert_eq!(
+                    info,
+                    elf64_r_info_ffi(sym, t),
+                    "sym: {sym:#x}, type: {t:#x}"
+                );
+                assert_eq!(libc::ELF64_R_SYM(info), elf64_r_sym_ffi(info));
+                assert_eq!(libc::ELF64_R_TYPE(info), elf64_r_type_ffi(info));
+                assert_eq!(libc::ELF64_R_SYM(info), sym);
+                assert_eq!(libc::ELF64_R_TYPE(info), t);
+            }
+        }
+    }
+}
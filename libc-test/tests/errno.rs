@@ -0,0 +1,37 @@
+//! Compare libc's `errno` and `set_errno` against C's `errno`.
+
+#![cfg(any(
+    all(
+        unix,
+        not(any(
+            target_os = "redox",
+            target_os = "qurt",
+            // WALI
+            all(target_os = "linux", target_family = "wasm")
+        ))
+    ),
+    windows
+))]
+
+use libc::c_int;
+
+extern "C" {
+    pub fn errno_ffi() -> c_int;
+    pub fn set_errno_ffi(value: c_int);
+}
+
+#[test]
+fn test_errno() {
+    for value in [0, libc::EINVAL, libc::ENOENT, libc::ERANGE, 4242] {
+        unsafe { set_errno_ffi(value) };
+        assert_eq!(libc::errno(), value);
+    }
+}
+
+#[test]
+fn test_set_errno() {
+    for value in [0, libc::EINVAL, libc::ENOENT, libc::ERANGE, 4242] {
+        libc::set_errno(value);
+        assert_eq!(unsafe { errno_ffi() }, value);
+    }
+}
@@ -0,0 +1,46 @@
+//! Round-trip the current thread's scheduling attributes through `sched_getattr` and
+//! `sched_setattr`.
+
+#![cfg(target_os = "linux")]
+
+use std::io;
+use std::mem::{
+    size_of,
+    zeroed,
+};
+
+use libc::{
+    c_uint,
+    sched_attr,
+};
+
+fn getattr() -> sched_attr {
+    unsafe {
+        let mut attr: sched_attr = zeroed();
+        let size = size_of::<sched_attr>() as c_uint;
+        let ret = libc::sched_getattr(0, &mut attr, size, 0);
+        assert_eq!(ret, 0, "sched_getattr: {}", io::Error::last_os_error());
+        attr
+    }
+}
+
+#[test]
+fn test_sched_attr_round_trip() {
+    let mut attr = getattr();
+    // The kernel reports the size of the layout it filled in, which is never larger than ours.
+    let size = attr.size;
+    assert!(
+        size != 0 && size as usize <= size_of::<sched_attr>(),
+        "size: {size}"
+    );
+
+    let ret = unsafe { libc::sched_setattr(0, &mut attr, 0) };
+    assert_eq!(ret, 0, "sched_setattr: {}", io::Error::last_os_error());
+
+    let new = getattr();
+    assert_eq!(new.size, size);
+    assert_eq!(new.sched_policy, attr.sched_policy);
+    assert_eq!(new.sched_flags, attr.sched_flags);
+    assert_eq!(new.sched_nice, attr.sched_nice);
+    assert_eq!(new.sched_priority, attr.sched_priority);
+}
@@ -0,0 +1,11 @@
+#![allow(bad_style, improper_ctypes, unused, deprecated)]
+
+use libc::*;
+
+#[cfg(target_os = "linux")]
+include!(concat!(env!("OUT_DIR"), "/linux_tcp.rs"));
+
+#[cfg(not(target_os = "linux"))]
+fn main() {
+    println!("PASSED 0 tests");
+}
@@ -0,0 +1,110 @@
+//! Check that `sigevent` notifications reach their target through `timer_create`.
+
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use std::sync::atomic::{
+    AtomicI32,
+    Ordering,
+};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use libc::{
+    c_int,
+    c_void,
+    sigevent,
+    timer_t,
+};
+
+const VALUE: c_int = 0x5a5a;
+
+unsafe fn arm(sev: &mut sigevent) -> timer_t {
+    let mut timer: timer_t = core::ptr::null_mut();
+    assert_eq!(
+        libc::timer_create(libc::CLOCK_MONOTONIC, sev, &mut timer),
+        0
+    );
+    let mut its: libc::itimerspec = core::mem::zeroed();
+    its.it_value.tv_nsec = 1_000_000;
+    assert_eq!(
+        libc::timer_settime(timer, 0, &its, core::ptr::null_mut()),
+        0
+    );
+    timer
+}
+
+#[test]
+fn test_thread_id() {
+    let signo = libc::SIGRTMIN();
+    let (tid_tx, tid_rx) = mpsc::channel();
+
+    let waiter = thread::spawn(move || unsafe {
+        let mut set: libc::sigset_t = core::mem::zeroed();
+        libc::sigemptyset(&mut set);
+        libc::sigaddset(&mut set, signo);
+        assert_eq!(
+            libc::pthread_sigmask(libc::SIG_BLOCK, &set, core::ptr::null_mut()),
+            0
+        );
+        tid_tx.send(libc::gettid()).unwrap();
+
+        let mut si: libc::siginfo_t = core::mem::zeroed();
+        let timeout = libc::timespec {
+            tv_sec: 5,
+            tv_nsec: 0,
+        };
+        assert_eq!(libc::sigtimedwait(&set, &mut si, &timeout), signo);
+        si
+    });
+
+    let tid = tid_rx.recv().unwrap();
+    unsafe {
+        let mut sev: sigevent = core::mem::zeroed();
+        sev.sigev_notify = libc::SIGEV_THREAD_ID;
+        sev.sigev_signo = signo;
+        sev.sigev_value.sival_ptr = VALUE as usize as *mut c_void;
+        sev.set_sigev_notify_thread_id(tid);
+        assert_eq!(sev.sigev_notify_thread_id(), tid);
+
+        let timer = arm(&mut sev);
+        let si = waiter.join().unwrap();
+        assert_eq!(si.si_signo, signo);
+        assert_eq!(si.si_code, libc::SI_TIMER);
+        assert_eq!(si.si_value().sival_ptr as usize, VALUE as usize);
+        assert_eq!(libc::timer_delete(timer), 0);
+    }
+}
+
+static NOTIFIED: AtomicI32 = AtomicI32::new(0);
+
+extern "C" fn notify(value: libc::sigval) {
+    let value = unsafe { value.sival_ptr } as usize as c_int;
+    NOTIFIED.store(value, Ordering::SeqCst);
+}
+
+#[test]
+fn test_thread_function() {
+    unsafe {
+        let mut sev: sigevent = core::mem::zeroed();
+        sev.sigev_notify = libc::SIGEV_THREAD;
+        sev.sigev_value.sival_ptr = VALUE as usize as *mut c_void;
+        sev.set_sigev_notify_function(Some(notify));
+        sev.set_sigev_notify_attributes(core::ptr::null_mut());
+        assert_eq!(
+            sev.sigev_notify_function().map(|f| f as *const ()),
+            Some(notify as *const ())
+        );
+        assert!(sev.sigev_notify_attributes().is_null());
+
+        let timer = arm(&mut sev);
+        for _ in 0..500 {
+            if NOTIFIED.load(Ordering::SeqCst) != 0 {
+                break;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(NOTIFIED.load(Ordering::SeqCst), VALUE);
+        assert_eq!(libc::timer_delete(timer), 0);
+    }
+}
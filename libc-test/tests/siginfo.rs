@@ -0,0 +1,84 @@
+//! Compare libc's `siginfo_t` accessors against the actual C macros.
+
+#![cfg(any(target_os = "android", target_os = "linux"))]
+
+use libc::{
+    c_int,
+    c_long,
+    c_short,
+    c_uint,
+    c_ulong,
+    c_void,
+    pid_t,
+    siginfo_t,
+    uid_t,
+};
+
+extern "C" {
+    fn si_pid_ffi(si: *const siginfo_t) -> pid_t;
+    fn si_uid_ffi(si: *const siginfo_t) -> uid_t;
+    fn si_timerid_ffi(si: *const siginfo_t) -> c_int;
+    fn si_overrun_ffi(si: *const siginfo_t) -> c_int;
+    fn si_value_ffi(si: *const siginfo_t) -> *mut c_void;
+    fn si_status_ffi(si: *const siginfo_t) -> c_int;
+    fn si_utime_ffi(si: *const siginfo_t) -> c_long;
+    fn si_stime_ffi(si: *const siginfo_t) -> c_long;
+    fn si_addr_ffi(si: *const siginfo_t) -> *mut c_void;
+    fn si_addr_lsb_ffi(si: *const siginfo_t) -> c_short;
+    fn si_lower_ffi(si: *const siginfo_t) -> *mut c_void;
+    fn si_upper_ffi(si: *const siginfo_t) -> *mut c_void;
+    fn si_pkey_ffi(si: *const siginfo_t) -> u32;
+    fn si_perf_data_ffi(si: *const siginfo_t) -> c_ulong;
+    fn si_perf_type_ffi(si: *const siginfo_t) -> u32;
+    fn si_perf_flags_ffi(si: *const siginfo_t) -> u32;
+    fn si_band_ffi(si: *const siginfo_t) -> c_long;
+    fn si_fd_ffi(si: *const siginfo_t) -> c_int;
+    fn si_call_addr_ffi(si: *const siginfo_t) -> *mut c_void;
+    fn si_syscall_ffi(si: *const siginfo_t) -> c_int;
+    fn si_arch_ffi(si: *const siginfo_t) -> c_uint;
+}
+
+/// A `siginfo_t` where every byte is distinct, so that reading any field at the wrong offset
+/// returns a different value.
+fn patterned() -> siginfo_t {
+    let mut si: siginfo_t = unsafe { core::mem::zeroed() };
+    let bytes = unsafe {
+        core::slice::from_raw_parts_mut(
+            (&mut si as *mut siginfo_t).cast::<u8>(),
+            core::mem::size_of::<siginfo_t>(),
+        )
+    };
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = i as u8 + 1;
+    }
+    si
+}
+
+#[test]
+fn test_accessors() {
+    let si = patterned();
+    let p: *const siginfo_t = &si;
+    unsafe {
+        assert_eq!(si.si_pid(), si_pid_ffi(p));
+        assert_eq!(si.si_uid(), si_uid_ffi(p));
+        assert_eq!(si.si_timerid(), si_timerid_ffi(p));
+        assert_eq!(si.si_overrun(), si_overrun_ffi(p));
+        assert_eq!(si.si_value().sival_ptr, si_value_ffi(p));
+        assert_eq!(si.si_status(), si_status_ffi(p));
+        assert_eq!(si.si_utime(), si_utime_ffi(p));
+        assert_eq!(si.si_stime(), si_stime_ffi(p));
+        assert_eq!(si.si_addr(), si_addr_ffi(p));
+        assert_eq!(si.si_addr_lsb(), si_addr_lsb_ffi(p));
+        assert_eq!(si.si_lower(), si_lower_ffi(p));
+        assert_eq!(si.si_upper(), si_upper_ffi(p));
+        assert_eq!(si.si_pkey(), si_pkey_ffi(p));
+        assert_eq!(si.si_perf_data(), si_perf_data_ffi(p));
+        assert_eq!(si.si_perf_type(), si_perf_type_ffi(p));
+        assert_eq!(si.si_perf_flags(), si_perf_flags_ffi(p));
+        assert_eq!(si.si_band(), si_band_ffi(p));
+        assert_eq!(si.si_fd(), si_fd_ffi(p));
+        assert_eq!(si.si_call_addr(), si_call_addr_ffi(p));
+        assert_eq!(si.si_syscall(), si_syscall_ffi(p));
+        assert_eq!(si.si_arch(), si_arch_ffi(p));
+    }
+}
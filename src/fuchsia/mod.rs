@@ -3183,6 +3183,14 @@ pub const MAP_HUGE_SHIFT: u32 = 26;
 // END_PUB_CONST
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno_location() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno_location() = value }
+    }
+
     pub unsafe fn FD_CLR(fd: c_int, set: *mut fd_set) -> () {
         let fd = fd as usize;
         let size = size_of_val(&(*set).fds_bits[0]) * 8;
@@ -385,6 +385,16 @@ pub const ENOTRECOVERABLE: i32 = 131;
 pub const ERFKILL: i32 = 132;
 pub const EHWPOISON: i32 = 133;
 
+f! {
+    pub safe fn errno() -> i32 {
+        unsafe { sys_errno() }
+    }
+
+    pub safe fn set_errno(errno: i32) -> () {
+        unsafe { sys_set_errno(errno) }
+    }
+}
+
 extern "C" {
     #[link_name = "sys_alloc"]
     pub fn alloc(size: usize, align: usize) -> *mut u8;
@@ -404,8 +414,9 @@ extern "C" {
     #[link_name = "sys_abort"]
     pub fn abort() -> !;
 
-    #[link_name = "sys_errno"]
-    pub fn errno() -> i32;
+    pub fn sys_errno() -> i32;
+
+    pub fn sys_set_errno(errno: i32);
 
     #[link_name = "sys_clock_gettime"]
     pub fn clock_gettime(clockid: clockid_t, tp: *mut timespec) -> i32;
@@ -0,0 +1,84 @@
+//! Header: `bluetooth/bluetooth.h`
+//!
+//! The Bluetooth socket ABI is defined by the kernel but its headers aren't exported as UAPI, so
+//! these follow the copies shipped by BlueZ.
+
+pub(crate) mod hci;
+pub(crate) mod l2cap;
+pub(crate) mod rfcomm;
+pub(crate) mod sco;
+
+use crate::prelude::*;
+
+s! {
+    #[repr(packed)]
+    pub struct bdaddr_t {
+        pub b: [u8; 6],
+    }
+
+    pub struct bt_security {
+        pub level: u8,
+        pub key_size: u8,
+    }
+
+    pub struct bt_power {
+        pub force_active: u8,
+    }
+
+    pub struct bt_voice {
+        pub setting: u16,
+    }
+}
+
+pub const BTPROTO_L2CAP: c_int = 0;
+pub const BTPROTO_HCI: c_int = 1;
+pub const BTPROTO_SCO: c_int = 2;
+pub const BTPROTO_RFCOMM: c_int = 3;
+pub const BTPROTO_BNEP: c_int = 4;
+pub const BTPROTO_CMTP: c_int = 5;
+pub const BTPROTO_HIDP: c_int = 6;
+pub const BTPROTO_AVDTP: c_int = 7;
+pub const BTPROTO_ISO: c_int = 8;
+
+pub const SOL_HCI: c_int = 0;
+pub const SOL_L2CAP: c_int = 6;
+pub const SOL_SCO: c_int = 17;
+pub const SOL_RFCOMM: c_int = 18;
+
+pub const BT_SECURITY: c_int = 4;
+
+pub const BT_SECURITY_SDP: u8 = 0;
+pub const BT_SECURITY_LOW: u8 = 1;
+pub const BT_SECURITY_MEDIUM: u8 = 2;
+pub const BT_SECURITY_HIGH: u8 = 3;
+pub const BT_SECURITY_FIPS: u8 = 4;
+
+pub const BT_DEFER_SETUP: c_int = 7;
+
+pub const BT_FLUSHABLE: c_int = 8;
+
+pub const BT_FLUSHABLE_OFF: u8 = 0;
+pub const BT_FLUSHABLE_ON: u8 = 1;
+
+pub const BT_POWER: c_int = 9;
+
+pub const BT_POWER_FORCE_ACTIVE_OFF: u8 = 0;
+pub const BT_POWER_FORCE_ACTIVE_ON: u8 = 1;
+
+pub const BT_CHANNEL_POLICY: c_int = 10;
+
+pub const BT_CHANNEL_POLICY_BREDR_ONLY: u32 = 0;
+pub const BT_CHANNEL_POLICY_BREDR_PREFERRED: u32 = 1;
+pub const BT_CHANNEL_POLICY_AMP_PREFERRED: u32 = 2;
+
+pub const BT_VOICE: c_int = 11;
+
+pub const BT_VOICE_TRANSPARENT: u16 = 0x0003;
+pub const BT_VOICE_CVSD_16BIT: u16 = 0x0060;
+
+pub const BT_SNDMTU: c_int = 12;
+pub const BT_RCVMTU: c_int = 13;
+
+pub const BDADDR_BREDR: u8 = 0x00;
+pub const BDADDR_LE_PUBLIC: u8 = 0x01;
+pub const BDADDR_LE_RANDOM: u8 = 0x02;
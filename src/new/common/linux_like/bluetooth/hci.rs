@@ -0,0 +1,115 @@
+//! Header: `bluetooth/hci.h`
+
+use crate::prelude::*;
+use crate::{
+    bdaddr_t,
+    Ioctl,
+    _IOR,
+    _IOW,
+};
+
+s! {
+    pub struct sockaddr_hci {
+        pub hci_family: crate::sa_family_t,
+        pub hci_dev: c_ushort,
+        pub hci_channel: c_ushort,
+    }
+
+    pub struct hci_filter {
+        pub type_mask: u32,
+        pub event_mask: [u32; 2],
+        pub opcode: u16,
+    }
+
+    pub struct hci_dev_req {
+        pub dev_id: u16,
+        pub dev_opt: u32,
+    }
+
+    pub struct hci_dev_list_req {
+        pub dev_num: u16,
+        pub dev_req: [hci_dev_req; 0],
+    }
+
+    pub struct hci_dev_stats {
+        pub err_rx: u32,
+        pub err_tx: u32,
+        pub cmd_tx: u32,
+        pub evt_rx: u32,
+        pub acl_tx: u32,
+        pub acl_rx: u32,
+        pub sco_tx: u32,
+        pub sco_rx: u32,
+        pub byte_rx: u32,
+        pub byte_tx: u32,
+    }
+
+    pub struct hci_dev_info {
+        pub dev_id: u16,
+        pub name: [c_char; 8],
+        pub bdaddr: bdaddr_t,
+        pub flags: u32,
+        pub type_: u8,
+        pub features: [u8; 8],
+        pub pkt_type: u32,
+        pub link_policy: u32,
+        pub link_mode: u32,
+        pub acl_mtu: u16,
+        pub acl_pkts: u16,
+        pub sco_mtu: u16,
+        pub sco_pkts: u16,
+        pub stat: hci_dev_stats,
+    }
+}
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const HCI_MAX_DEV: c_int = 16;
+
+pub const HCI_DEV_NONE: c_ushort = 0xffff;
+
+pub const HCI_CHANNEL_RAW: c_ushort = 0;
+pub const HCI_CHANNEL_USER: c_ushort = 1;
+pub const HCI_CHANNEL_MONITOR: c_ushort = 2;
+pub const HCI_CHANNEL_CONTROL: c_ushort = 3;
+pub const HCI_CHANNEL_LOGGING: c_ushort = 4;
+
+pub const HCI_DATA_DIR: c_int = 1;
+pub const HCI_FILTER: c_int = 2;
+pub const HCI_TIME_STAMP: c_int = 3;
+
+pub const HCI_CMSG_DIR: c_int = 0x0001;
+pub const HCI_CMSG_TSTAMP: c_int = 0x0002;
+
+pub const HCI_FLT_TYPE_BITS: c_int = 31;
+pub const HCI_FLT_EVENT_BITS: c_int = 63;
+pub const HCI_FLT_OCF_BITS: c_int = 127;
+pub const HCI_FLT_OGF_BITS: c_int = 63;
+
+const HCI_IOC_TYPE: u32 = b'H' as u32;
+
+pub const HCIDEVUP: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 201);
+pub const HCIDEVDOWN: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 202);
+pub const HCIDEVRESET: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 203);
+pub const HCIDEVRESTAT: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 204);
+
+pub const HCIGETDEVLIST: Ioctl = _IOR::<c_int>(HCI_IOC_TYPE, 210);
+pub const HCIGETDEVINFO: Ioctl = _IOR::<c_int>(HCI_IOC_TYPE, 211);
+pub const HCIGETCONNLIST: Ioctl = _IOR::<c_int>(HCI_IOC_TYPE, 212);
+pub const HCIGETCONNINFO: Ioctl = _IOR::<c_int>(HCI_IOC_TYPE, 213);
+pub const HCIGETAUTHINFO: Ioctl = _IOR::<c_int>(HCI_IOC_TYPE, 215);
+
+pub const HCISETRAW: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 220);
+pub const HCISETSCAN: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 221);
+pub const HCISETAUTH: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 222);
+pub const HCISETENCRYPT: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 223);
+pub const HCISETPTYPE: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 224);
+pub const HCISETLINKPOL: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 225);
+pub const HCISETLINKMODE: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 226);
+pub const HCISETACLMTU: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 227);
+pub const HCISETSCOMTU: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 228);
+
+pub const HCIBLOCKADDR: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 230);
+pub const HCIUNBLOCKADDR: Ioctl = _IOW::<c_int>(HCI_IOC_TYPE, 231);
+
+pub const HCIINQUIRY: Ioctl = _IOR::<c_int>(HCI_IOC_TYPE, 240);
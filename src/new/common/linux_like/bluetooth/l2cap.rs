@@ -0,0 +1,40 @@
+//! Header: `bluetooth/l2cap.h`
+
+use crate::bdaddr_t;
+use crate::prelude::*;
+
+s! {
+    pub struct sockaddr_l2 {
+        pub l2_family: crate::sa_family_t,
+        pub l2_psm: c_ushort,
+        pub l2_bdaddr: bdaddr_t,
+        pub l2_cid: c_ushort,
+        pub l2_bdaddr_type: u8,
+    }
+
+    pub struct l2cap_options {
+        pub omtu: u16,
+        pub imtu: u16,
+        pub flush_to: u16,
+        pub mode: u8,
+        pub fcs: u8,
+        pub max_tx: u8,
+        pub txwin_size: u16,
+    }
+
+    pub struct l2cap_conninfo {
+        pub hci_handle: u16,
+        pub dev_class: [u8; 3],
+    }
+}
+
+pub const L2CAP_OPTIONS: c_int = 0x01;
+pub const L2CAP_CONNINFO: c_int = 0x02;
+pub const L2CAP_LM: c_int = 0x03;
+
+pub const L2CAP_LM_MASTER: c_int = 0x0001;
+pub const L2CAP_LM_AUTH: c_int = 0x0002;
+pub const L2CAP_LM_ENCRYPT: c_int = 0x0004;
+pub const L2CAP_LM_TRUSTED: c_int = 0x0008;
+pub const L2CAP_LM_RELIABLE: c_int = 0x0010;
+pub const L2CAP_LM_SECURE: c_int = 0x0020;
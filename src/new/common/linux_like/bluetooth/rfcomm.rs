@@ -0,0 +1,27 @@
+//! Header: `bluetooth/rfcomm.h`
+
+use crate::bdaddr_t;
+use crate::prelude::*;
+
+s! {
+    pub struct sockaddr_rc {
+        pub rc_family: crate::sa_family_t,
+        pub rc_bdaddr: bdaddr_t,
+        pub rc_channel: u8,
+    }
+
+    pub struct rfcomm_conninfo {
+        pub hci_handle: u16,
+        pub dev_class: [u8; 3],
+    }
+}
+
+pub const RFCOMM_CONNINFO: c_int = 0x02;
+pub const RFCOMM_LM: c_int = 0x03;
+
+pub const RFCOMM_LM_MASTER: c_int = 0x0001;
+pub const RFCOMM_LM_AUTH: c_int = 0x0002;
+pub const RFCOMM_LM_ENCRYPT: c_int = 0x0004;
+pub const RFCOMM_LM_TRUSTED: c_int = 0x0008;
+pub const RFCOMM_LM_RELIABLE: c_int = 0x0010;
+pub const RFCOMM_LM_SECURE: c_int = 0x0020;
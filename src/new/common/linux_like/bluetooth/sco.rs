@@ -0,0 +1,23 @@
+//! Header: `bluetooth/sco.h`
+
+use crate::bdaddr_t;
+use crate::prelude::*;
+
+s! {
+    pub struct sockaddr_sco {
+        pub sco_family: crate::sa_family_t,
+        pub sco_bdaddr: bdaddr_t,
+    }
+
+    pub struct sco_options {
+        pub mtu: u16,
+    }
+
+    pub struct sco_conninfo {
+        pub hci_handle: u16,
+        pub dev_class: [u8; 3],
+    }
+}
+
+pub const SCO_OPTIONS: c_int = 0x01;
+pub const SCO_CONNINFO: c_int = 0x02;
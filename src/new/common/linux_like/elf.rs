@@ -0,0 +1,696 @@
+//! Header: `elf.h`
+//!
+//! This header is provided by the C library rather than the kernel's UAPI. The definitions are
+//! shared by glibc, musl and uClibc; the basic types and the file, program and section header
+//! structures are defined alongside the rest of the Linux API.
+
+use crate::prelude::*;
+use crate::{
+    Elf32_Addr,
+    Elf32_Half,
+    Elf32_Sword,
+    Elf32_Word,
+    Elf64_Addr,
+    Elf64_Half,
+    Elf64_Sxword,
+    Elf64_Word,
+    Elf64_Xword,
+};
+
+pub type Elf32_Versym = Elf32_Half;
+pub type Elf64_Versym = Elf64_Half;
+
+s! {
+    pub struct Elf32_Chdr {
+        pub ch_type: Elf32_Word,
+        pub ch_size: Elf32_Word,
+        pub ch_addralign: Elf32_Word,
+    }
+
+    pub struct Elf64_Chdr {
+        pub ch_type: Elf64_Word,
+        pub ch_reserved: Elf64_Word,
+        pub ch_size: Elf64_Xword,
+        pub ch_addralign: Elf64_Xword,
+    }
+
+    pub struct Elf32_Nhdr {
+        pub n_namesz: Elf32_Word,
+        pub n_descsz: Elf32_Word,
+        pub n_type: Elf32_Word,
+    }
+
+    pub struct Elf64_Nhdr {
+        pub n_namesz: Elf64_Word,
+        pub n_descsz: Elf64_Word,
+        pub n_type: Elf64_Word,
+    }
+
+    pub struct Elf32_Verdef {
+        pub vd_version: Elf32_Half,
+        pub vd_flags: Elf32_Half,
+        pub vd_ndx: Elf32_Half,
+        pub vd_cnt: Elf32_Half,
+        pub vd_hash: Elf32_Word,
+        pub vd_aux: Elf32_Word,
+        pub vd_next: Elf32_Word,
+    }
+
+    pub struct Elf64_Verdef {
+        pub vd_version: Elf64_Half,
+        pub vd_flags: Elf64_Half,
+        pub vd_ndx: Elf64_Half,
+        pub vd_cnt: Elf64_Half,
+        pub vd_hash: Elf64_Word,
+        pub vd_aux: Elf64_Word,
+        pub vd_next: Elf64_Word,
+    }
+
+    pub struct Elf32_Verdaux {
+        pub vda_name: Elf32_Word,
+        pub vda_next: Elf32_Word,
+    }
+
+    pub struct Elf64_Verdaux {
+        pub vda_name: Elf64_Word,
+        pub vda_next: Elf64_Word,
+    }
+
+    pub struct Elf32_Verneed {
+        pub vn_version: Elf32_Half,
+        pub vn_cnt: Elf32_Half,
+        pub vn_file: Elf32_Word,
+        pub vn_aux: Elf32_Word,
+        pub vn_next: Elf32_Word,
+    }
+
+    pub struct Elf64_Verneed {
+        pub vn_version: Elf64_Half,
+        pub vn_cnt: Elf64_Half,
+        pub vn_file: Elf64_Word,
+        pub vn_aux: Elf64_Word,
+        pub vn_next: Elf64_Word,
+    }
+
+    pub struct Elf32_Vernaux {
+        pub vna_hash: Elf32_Word,
+        pub vna_flags: Elf32_Half,
+        pub vna_other: Elf32_Half,
+        pub vna_name: Elf32_Word,
+        pub vna_next: Elf32_Word,
+    }
+
+    pub struct Elf64_Vernaux {
+        pub vna_hash: Elf64_Word,
+        pub vna_flags: Elf64_Half,
+        pub vna_other: Elf64_Half,
+        pub vna_name: Elf64_Word,
+        pub vna_next: Elf64_Word,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_elf32_dyn_union {
+        pub d_val: Elf32_Word,
+        pub d_ptr: Elf32_Addr,
+    }
+
+    pub struct Elf32_Dyn {
+        pub d_tag: Elf32_Sword,
+        pub d_un: __c_anonymous_elf32_dyn_union,
+    }
+
+    pub union __c_anonymous_elf64_dyn_union {
+        pub d_val: Elf64_Xword,
+        pub d_ptr: Elf64_Addr,
+    }
+
+    pub struct Elf64_Dyn {
+        pub d_tag: Elf64_Sxword,
+        pub d_un: __c_anonymous_elf64_dyn_union,
+    }
+
+    pub union __c_anonymous_elf32_auxv_union {
+        pub a_val: u32,
+    }
+
+    pub struct Elf32_auxv_t {
+        pub a_type: u32,
+        pub a_un: __c_anonymous_elf32_auxv_union,
+    }
+
+    pub union __c_anonymous_elf64_auxv_union {
+        pub a_val: u64,
+    }
+
+    pub struct Elf64_auxv_t {
+        pub a_type: u64,
+        pub a_un: __c_anonymous_elf64_auxv_union,
+    }
+}
+
+// Special section indices.
+pub const SHN_UNDEF: u16 = 0;
+pub const SHN_LORESERVE: u16 = 0xff00;
+pub const SHN_LOPROC: u16 = 0xff00;
+pub const SHN_BEFORE: u16 = 0xff00;
+pub const SHN_AFTER: u16 = 0xff01;
+pub const SHN_HIPROC: u16 = 0xff1f;
+pub const SHN_LOOS: u16 = 0xff20;
+pub const SHN_HIOS: u16 = 0xff3f;
+pub const SHN_ABS: u16 = 0xfff1;
+pub const SHN_COMMON: u16 = 0xfff2;
+pub const SHN_XINDEX: u16 = 0xffff;
+pub const SHN_HIRESERVE: u16 = 0xffff;
+
+// Legal values for sh_type (section type).
+pub const SHT_NULL: u32 = 0;
+pub const SHT_PROGBITS: u32 = 1;
+pub const SHT_SYMTAB: u32 = 2;
+pub const SHT_STRTAB: u32 = 3;
+pub const SHT_RELA: u32 = 4;
+pub const SHT_HASH: u32 = 5;
+pub const SHT_DYNAMIC: u32 = 6;
+pub const SHT_NOTE: u32 = 7;
+pub const SHT_NOBITS: u32 = 8;
+pub const SHT_REL: u32 = 9;
+pub const SHT_SHLIB: u32 = 10;
+pub const SHT_DYNSYM: u32 = 11;
+pub const SHT_INIT_ARRAY: u32 = 14;
+pub const SHT_FINI_ARRAY: u32 = 15;
+pub const SHT_PREINIT_ARRAY: u32 = 16;
+pub const SHT_GROUP: u32 = 17;
+pub const SHT_SYMTAB_SHNDX: u32 = 18;
+pub const SHT_RELR: u32 = 19;
+pub const SHT_LOOS: u32 = 0x60000000;
+pub const SHT_GNU_ATTRIBUTES: u32 = 0x6ffffff5;
+pub const SHT_GNU_HASH: u32 = 0x6ffffff6;
+pub const SHT_GNU_LIBLIST: u32 = 0x6ffffff7;
+pub const SHT_CHECKSUM: u32 = 0x6ffffff8;
+pub const SHT_LOSUNW: u32 = 0x6ffffffa;
+pub const SHT_SUNW_move: u32 = 0x6ffffffa;
+pub const SHT_SUNW_COMDAT: u32 = 0x6ffffffb;
+pub const SHT_SUNW_syminfo: u32 = 0x6ffffffc;
+pub const SHT_GNU_verdef: u32 = 0x6ffffffd;
+pub const SHT_GNU_verneed: u32 = 0x6ffffffe;
+pub const SHT_GNU_versym: u32 = 0x6fffffff;
+pub const SHT_HISUNW: u32 = 0x6fffffff;
+pub const SHT_HIOS: u32 = 0x6fffffff;
+pub const SHT_LOPROC: u32 = 0x70000000;
+pub const SHT_HIPROC: u32 = 0x7fffffff;
+pub const SHT_LOUSER: u32 = 0x80000000;
+pub const SHT_HIUSER: u32 = 0x8fffffff;
+
+// Legal values for sh_flags (section flags).
+pub const SHF_WRITE: u32 = 1 << 0;
+pub const SHF_ALLOC: u32 = 1 << 1;
+pub const SHF_EXECINSTR: u32 = 1 << 2;
+pub const SHF_MERGE: u32 = 1 << 4;
+pub const SHF_STRINGS: u32 = 1 << 5;
+pub const SHF_INFO_LINK: u32 = 1 << 6;
+pub const SHF_LINK_ORDER: u32 = 1 << 7;
+pub const SHF_OS_NONCONFORMING: u32 = 1 << 8;
+pub const SHF_GROUP: u32 = 1 << 9;
+pub const SHF_TLS: u32 = 1 << 10;
+pub const SHF_COMPRESSED: u32 = 1 << 11;
+pub const SHF_MASKOS: u32 = 0x0ff00000;
+pub const SHF_MASKPROC: u32 = 0xf0000000;
+pub const SHF_GNU_RETAIN: u32 = 1 << 21;
+pub const SHF_ORDERED: u32 = 1 << 30;
+pub const SHF_EXCLUDE: u32 = 1 << 31;
+
+// Legal values for ch_type (compression algorithm).
+pub const ELFCOMPRESS_ZLIB: u32 = 1;
+pub const ELFCOMPRESS_LOOS: u32 = 0x60000000;
+pub const ELFCOMPRESS_HIOS: u32 = 0x6fffffff;
+pub const ELFCOMPRESS_LOPROC: u32 = 0x70000000;
+pub const ELFCOMPRESS_HIPROC: u32 = 0x7fffffff;
+
+// Legal values for ST_BIND subfield of st_info (symbol binding).
+pub const STB_LOCAL: u8 = 0;
+pub const STB_GLOBAL: u8 = 1;
+pub const STB_WEAK: u8 = 2;
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const STB_NUM: u8 = 3;
+pub const STB_LOOS: u8 = 10;
+pub const STB_GNU_UNIQUE: u8 = 10;
+pub const STB_HIOS: u8 = 12;
+pub const STB_LOPROC: u8 = 13;
+pub const STB_HIPROC: u8 = 15;
+
+// Legal values for ST_TYPE subfield of st_info (symbol type).
+pub const STT_NOTYPE: u8 = 0;
+pub const STT_OBJECT: u8 = 1;
+pub const STT_FUNC: u8 = 2;
+pub const STT_SECTION: u8 = 3;
+pub const STT_FILE: u8 = 4;
+pub const STT_COMMON: u8 = 5;
+pub const STT_TLS: u8 = 6;
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const STT_NUM: u8 = 7;
+pub const STT_LOOS: u8 = 10;
+pub const STT_GNU_IFUNC: u8 = 10;
+pub const STT_HIOS: u8 = 12;
+pub const STT_LOPROC: u8 = 13;
+pub const STT_HIPROC: u8 = 15;
+
+// Symbol table indices.
+pub const STN_UNDEF: u32 = 0;
+
+// Symbol visibility specification encoded in the st_other field.
+pub const STV_DEFAULT: u8 = 0;
+pub const STV_INTERNAL: u8 = 1;
+pub const STV_HIDDEN: u8 = 2;
+pub const STV_PROTECTED: u8 = 3;
+
+// Legal values for d_tag (dynamic entry type).
+pub const DT_NULL: c_long = 0;
+pub const DT_NEEDED: c_long = 1;
+pub const DT_PLTRELSZ: c_long = 2;
+pub const DT_PLTGOT: c_long = 3;
+pub const DT_HASH: c_long = 4;
+pub const DT_STRTAB: c_long = 5;
+pub const DT_SYMTAB: c_long = 6;
+pub const DT_RELA: c_long = 7;
+pub const DT_RELASZ: c_long = 8;
+pub const DT_RELAENT: c_long = 9;
+pub const DT_STRSZ: c_long = 10;
+pub const DT_SYMENT: c_long = 11;
+pub const DT_INIT: c_long = 12;
+pub const DT_FINI: c_long = 13;
+pub const DT_SONAME: c_long = 14;
+pub const DT_RPATH: c_long = 15;
+pub const DT_SYMBOLIC: c_long = 16;
+pub const DT_REL: c_long = 17;
+pub const DT_RELSZ: c_long = 18;
+pub const DT_RELENT: c_long = 19;
+pub const DT_PLTREL: c_long = 20;
+pub const DT_DEBUG: c_long = 21;
+pub const DT_TEXTREL: c_long = 22;
+pub const DT_JMPREL: c_long = 23;
+pub const DT_BIND_NOW: c_long = 24;
+pub const DT_INIT_ARRAY: c_long = 25;
+pub const DT_FINI_ARRAY: c_long = 26;
+pub const DT_INIT_ARRAYSZ: c_long = 27;
+pub const DT_FINI_ARRAYSZ: c_long = 28;
+pub const DT_RUNPATH: c_long = 29;
+pub const DT_FLAGS: c_long = 30;
+pub const DT_ENCODING: c_long = 32;
+pub const DT_PREINIT_ARRAY: c_long = 32;
+pub const DT_PREINIT_ARRAYSZ: c_long = 33;
+pub const DT_SYMTAB_SHNDX: c_long = 34;
+pub const DT_RELRSZ: c_long = 35;
+pub const DT_RELR: c_long = 36;
+pub const DT_RELRENT: c_long = 37;
+pub const DT_LOOS: c_long = 0x6000000d;
+pub const DT_HIOS: c_long = 0x6ffff000;
+pub const DT_LOPROC: c_long = 0x70000000;
+pub const DT_HIPROC: c_long = 0x7fffffff;
+pub const DT_VALRNGLO: c_long = 0x6ffffd00;
+pub const DT_GNU_PRELINKED: c_long = 0x6ffffdf5;
+pub const DT_GNU_CONFLICTSZ: c_long = 0x6ffffdf6;
+pub const DT_GNU_LIBLISTSZ: c_long = 0x6ffffdf7;
+pub const DT_CHECKSUM: c_long = 0x6ffffdf8;
+pub const DT_PLTPADSZ: c_long = 0x6ffffdf9;
+pub const DT_MOVEENT: c_long = 0x6ffffdfa;
+pub const DT_MOVESZ: c_long = 0x6ffffdfb;
+pub const DT_FEATURE_1: c_long = 0x6ffffdfc;
+pub const DT_POSFLAG_1: c_long = 0x6ffffdfd;
+pub const DT_SYMINSZ: c_long = 0x6ffffdfe;
+pub const DT_SYMINENT: c_long = 0x6ffffdff;
+pub const DT_VALRNGHI: c_long = 0x6ffffdff;
+pub const DT_ADDRRNGLO: c_long = 0x6ffffe00;
+pub const DT_GNU_HASH: c_long = 0x6ffffef5;
+pub const DT_TLSDESC_PLT: c_long = 0x6ffffef6;
+pub const DT_TLSDESC_GOT: c_long = 0x6ffffef7;
+pub const DT_GNU_CONFLICT: c_long = 0x6ffffef8;
+pub const DT_GNU_LIBLIST: c_long = 0x6ffffef9;
+pub const DT_CONFIG: c_long = 0x6ffffefa;
+pub const DT_DEPAUDIT: c_long = 0x6ffffefb;
+pub const DT_AUDIT: c_long = 0x6ffffefc;
+pub const DT_PLTPAD: c_long = 0x6ffffefd;
+pub const DT_MOVETAB: c_long = 0x6ffffefe;
+pub const DT_SYMINFO: c_long = 0x6ffffeff;
+pub const DT_ADDRRNGHI: c_long = 0x6ffffeff;
+pub const DT_VERSYM: c_long = 0x6ffffff0;
+pub const DT_RELACOUNT: c_long = 0x6ffffff9;
+pub const DT_RELCOUNT: c_long = 0x6ffffffa;
+pub const DT_FLAGS_1: c_long = 0x6ffffffb;
+pub const DT_VERDEF: c_long = 0x6ffffffc;
+pub const DT_VERDEFNUM: c_long = 0x6ffffffd;
+pub const DT_VERNEED: c_long = 0x6ffffffe;
+pub const DT_VERNEEDNUM: c_long = 0x6fffffff;
+pub const DT_AUXILIARY: c_long = 0x7ffffffd;
+pub const DT_FILTER: c_long = 0x7fffffff;
+
+// State flags selectable in the `d_un.d_val` element of the DT_FLAGS_1 entry.
+pub const DF_1_NOW: u32 = 0x00000001;
+pub const DF_1_GLOBAL: u32 = 0x00000002;
+pub const DF_1_GROUP: u32 = 0x00000004;
+pub const DF_1_NODELETE: u32 = 0x00000008;
+pub const DF_1_LOADFLTR: u32 = 0x00000010;
+pub const DF_1_INITFIRST: u32 = 0x00000020;
+pub const DF_1_NOOPEN: u32 = 0x00000040;
+pub const DF_1_ORIGIN: u32 = 0x00000080;
+pub const DF_1_DIRECT: u32 = 0x00000100;
+pub const DF_1_TRANS: u32 = 0x00000200;
+pub const DF_1_INTERPOSE: u32 = 0x00000400;
+pub const DF_1_NODEFLIB: u32 = 0x00000800;
+pub const DF_1_NODUMP: u32 = 0x00001000;
+pub const DF_1_CONFALT: u32 = 0x00002000;
+pub const DF_1_ENDFILTEE: u32 = 0x00004000;
+pub const DF_1_DISPRELDNE: u32 = 0x00008000;
+pub const DF_1_DISPRELPND: u32 = 0x00010000;
+pub const DF_1_NODIRECT: u32 = 0x00020000;
+pub const DF_1_IGNMULDEF: u32 = 0x00040000;
+pub const DF_1_NOKSYMS: u32 = 0x00080000;
+pub const DF_1_NOHDR: u32 = 0x00100000;
+pub const DF_1_EDITED: u32 = 0x00200000;
+pub const DF_1_NORELOC: u32 = 0x00400000;
+pub const DF_1_SYMINTPOSE: u32 = 0x00800000;
+pub const DF_1_GLOBAUDIT: u32 = 0x01000000;
+pub const DF_1_SINGLETON: u32 = 0x02000000;
+pub const DF_1_STUB: u32 = 0x04000000;
+pub const DF_1_PIE: u32 = 0x08000000;
+pub const DF_1_KMOD: u32 = 0x10000000;
+pub const DF_1_WEAKFILTER: u32 = 0x20000000;
+pub const DF_1_NOCOMMON: u32 = 0x40000000;
+
+// Values of `d_un.d_val` in the DT_FLAGS entry.
+pub const DF_ORIGIN: u32 = 0x00000001;
+pub const DF_SYMBOLIC: u32 = 0x00000002;
+pub const DF_TEXTREL: u32 = 0x00000004;
+pub const DF_BIND_NOW: u32 = 0x00000008;
+pub const DF_STATIC_TLS: u32 = 0x00000010;
+
+// Versioning constants.
+pub const VER_DEF_NONE: u16 = 0;
+pub const VER_DEF_CURRENT: u16 = 1;
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const VER_DEF_NUM: u16 = 2;
+pub const VER_FLG_BASE: u16 = 0x1;
+pub const VER_FLG_WEAK: u16 = 0x2;
+pub const VER_NDX_LOCAL: u16 = 0;
+pub const VER_NDX_GLOBAL: u16 = 1;
+pub const VER_NDX_LORESERVE: u16 = 0xff00;
+pub const VER_NDX_ELIMINATE: u16 = 0xff01;
+pub const VER_NEED_NONE: u16 = 0;
+pub const VER_NEED_CURRENT: u16 = 1;
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const VER_NEED_NUM: u16 = 2;
+
+// Note types for the GNU note section.
+pub const NT_GNU_ABI_TAG: u32 = 1;
+pub const NT_GNU_HWCAP: u32 = 2;
+pub const NT_GNU_BUILD_ID: u32 = 3;
+pub const NT_GNU_GOLD_VERSION: u32 = 4;
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+
+// Properties in the NT_GNU_PROPERTY_TYPE_0 note.
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+pub const GNU_PROPERTY_UINT32_AND_LO: u32 = 0xb0000000;
+pub const GNU_PROPERTY_UINT32_AND_HI: u32 = 0xb0007fff;
+pub const GNU_PROPERTY_UINT32_OR_LO: u32 = 0xb0008000;
+pub const GNU_PROPERTY_UINT32_OR_HI: u32 = 0xb000ffff;
+pub const GNU_PROPERTY_1_NEEDED: u32 = GNU_PROPERTY_UINT32_OR_LO;
+pub const GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS: u32 = 1 << 0;
+pub const GNU_PROPERTY_LOPROC: u32 = 0xc0000000;
+pub const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff;
+pub const GNU_PROPERTY_LOUSER: u32 = 0xe0000000;
+pub const GNU_PROPERTY_HIUSER: u32 = 0xffffffff;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 1 << 0;
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 1 << 1;
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = 0xc0010002;
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = 0xc0008002;
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = 0xc0000002;
+pub const GNU_PROPERTY_X86_ISA_1_BASELINE: u32 = 1 << 0;
+pub const GNU_PROPERTY_X86_ISA_1_V2: u32 = 1 << 1;
+pub const GNU_PROPERTY_X86_ISA_1_V3: u32 = 1 << 2;
+pub const GNU_PROPERTY_X86_ISA_1_V4: u32 = 1 << 3;
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 1 << 0;
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 1 << 1;
+
+// AMD x86-64 relocations.
+pub const R_X86_64_NONE: u32 = 0;
+pub const R_X86_64_64: u32 = 1;
+pub const R_X86_64_PC32: u32 = 2;
+pub const R_X86_64_GOT32: u32 = 3;
+pub const R_X86_64_PLT32: u32 = 4;
+pub const R_X86_64_COPY: u32 = 5;
+pub const R_X86_64_GLOB_DAT: u32 = 6;
+pub const R_X86_64_JUMP_SLOT: u32 = 7;
+pub const R_X86_64_RELATIVE: u32 = 8;
+pub const R_X86_64_GOTPCREL: u32 = 9;
+pub const R_X86_64_32: u32 = 10;
+pub const R_X86_64_32S: u32 = 11;
+pub const R_X86_64_16: u32 = 12;
+pub const R_X86_64_PC16: u32 = 13;
+pub const R_X86_64_8: u32 = 14;
+pub const R_X86_64_PC8: u32 = 15;
+pub const R_X86_64_DTPMOD64: u32 = 16;
+pub const R_X86_64_DTPOFF64: u32 = 17;
+pub const R_X86_64_TPOFF64: u32 = 18;
+pub const R_X86_64_TLSGD: u32 = 19;
+pub const R_X86_64_TLSLD: u32 = 20;
+pub const R_X86_64_DTPOFF32: u32 = 21;
+pub const R_X86_64_GOTTPOFF: u32 = 22;
+pub const R_X86_64_TPOFF32: u32 = 23;
+pub const R_X86_64_PC64: u32 = 24;
+pub const R_X86_64_GOTOFF64: u32 = 25;
+pub const R_X86_64_GOTPC32: u32 = 26;
+pub const R_X86_64_GOT64: u32 = 27;
+pub const R_X86_64_GOTPCREL64: u32 = 28;
+pub const R_X86_64_GOTPC64: u32 = 29;
+pub const R_X86_64_GOTPLT64: u32 = 30;
+pub const R_X86_64_PLTOFF64: u32 = 31;
+pub const R_X86_64_SIZE32: u32 = 32;
+pub const R_X86_64_SIZE64: u32 = 33;
+pub const R_X86_64_GOTPC32_TLSDESC: u32 = 34;
+pub const R_X86_64_TLSDESC_CALL: u32 = 35;
+pub const R_X86_64_TLSDESC: u32 = 36;
+pub const R_X86_64_IRELATIVE: u32 = 37;
+pub const R_X86_64_RELATIVE64: u32 = 38;
+pub const R_X86_64_GOTPCRELX: u32 = 41;
+pub const R_X86_64_REX_GOTPCRELX: u32 = 42;
+
+// AArch64 relocations.
+pub const R_AARCH64_NONE: u32 = 0;
+pub const R_AARCH64_ABS64: u32 = 257;
+pub const R_AARCH64_ABS32: u32 = 258;
+pub const R_AARCH64_ABS16: u32 = 259;
+pub const R_AARCH64_PREL64: u32 = 260;
+pub const R_AARCH64_PREL32: u32 = 261;
+pub const R_AARCH64_PREL16: u32 = 262;
+pub const R_AARCH64_MOVW_UABS_G0: u32 = 263;
+pub const R_AARCH64_MOVW_UABS_G0_NC: u32 = 264;
+pub const R_AARCH64_MOVW_UABS_G1: u32 = 265;
+pub const R_AARCH64_MOVW_UABS_G1_NC: u32 = 266;
+pub const R_AARCH64_MOVW_UABS_G2: u32 = 267;
+pub const R_AARCH64_MOVW_UABS_G2_NC: u32 = 268;
+pub const R_AARCH64_MOVW_UABS_G3: u32 = 269;
+pub const R_AARCH64_MOVW_SABS_G0: u32 = 270;
+pub const R_AARCH64_MOVW_SABS_G1: u32 = 271;
+pub const R_AARCH64_MOVW_SABS_G2: u32 = 272;
+pub const R_AARCH64_LD_PREL_LO19: u32 = 273;
+pub const R_AARCH64_ADR_PREL_LO21: u32 = 274;
+pub const R_AARCH64_ADR_PREL_PG_HI21: u32 = 275;
+pub const R_AARCH64_ADR_PREL_PG_HI21_NC: u32 = 276;
+pub const R_AARCH64_ADD_ABS_LO12_NC: u32 = 277;
+pub const R_AARCH64_LDST8_ABS_LO12_NC: u32 = 278;
+pub const R_AARCH64_TSTBR14: u32 = 279;
+pub const R_AARCH64_CONDBR19: u32 = 280;
+pub const R_AARCH64_JUMP26: u32 = 282;
+pub const R_AARCH64_CALL26: u32 = 283;
+pub const R_AARCH64_LDST16_ABS_LO12_NC: u32 = 284;
+pub const R_AARCH64_LDST32_ABS_LO12_NC: u32 = 285;
+pub const R_AARCH64_LDST64_ABS_LO12_NC: u32 = 286;
+pub const R_AARCH64_MOVW_PREL_G0: u32 = 287;
+pub const R_AARCH64_MOVW_PREL_G0_NC: u32 = 288;
+pub const R_AARCH64_MOVW_PREL_G1: u32 = 289;
+pub const R_AARCH64_MOVW_PREL_G1_NC: u32 = 290;
+pub const R_AARCH64_MOVW_PREL_G2: u32 = 291;
+pub const R_AARCH64_MOVW_PREL_G2_NC: u32 = 292;
+pub const R_AARCH64_MOVW_PREL_G3: u32 = 293;
+pub const R_AARCH64_LDST128_ABS_LO12_NC: u32 = 299;
+pub const R_AARCH64_MOVW_GOTOFF_G0: u32 = 300;
+pub const R_AARCH64_MOVW_GOTOFF_G0_NC: u32 = 301;
+pub const R_AARCH64_MOVW_GOTOFF_G1: u32 = 302;
+pub const R_AARCH64_MOVW_GOTOFF_G1_NC: u32 = 303;
+pub const R_AARCH64_MOVW_GOTOFF_G2: u32 = 304;
+pub const R_AARCH64_MOVW_GOTOFF_G2_NC: u32 = 305;
+pub const R_AARCH64_MOVW_GOTOFF_G3: u32 = 306;
+pub const R_AARCH64_GOTREL64: u32 = 307;
+pub const R_AARCH64_GOTREL32: u32 = 308;
+pub const R_AARCH64_GOT_LD_PREL19: u32 = 309;
+pub const R_AARCH64_LD64_GOTOFF_LO15: u32 = 310;
+pub const R_AARCH64_ADR_GOT_PAGE: u32 = 311;
+pub const R_AARCH64_LD64_GOT_LO12_NC: u32 = 312;
+pub const R_AARCH64_LD64_GOTPAGE_LO15: u32 = 313;
+pub const R_AARCH64_TLSGD_ADR_PREL21: u32 = 512;
+pub const R_AARCH64_TLSGD_ADR_PAGE21: u32 = 513;
+pub const R_AARCH64_TLSGD_ADD_LO12_NC: u32 = 514;
+pub const R_AARCH64_TLSGD_MOVW_G1: u32 = 515;
+pub const R_AARCH64_TLSGD_MOVW_G0_NC: u32 = 516;
+pub const R_AARCH64_TLSLD_ADR_PREL21: u32 = 517;
+pub const R_AARCH64_TLSLD_ADR_PAGE21: u32 = 518;
+pub const R_AARCH64_TLSLD_ADD_LO12_NC: u32 = 519;
+pub const R_AARCH64_TLSLD_MOVW_G1: u32 = 520;
+pub const R_AARCH64_TLSLD_MOVW_G0_NC: u32 = 521;
+pub const R_AARCH64_TLSLD_LD_PREL19: u32 = 522;
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G2: u32 = 523;
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G1: u32 = 524;
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G1_NC: u32 = 525;
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G0: u32 = 526;
+pub const R_AARCH64_TLSLD_MOVW_DTPREL_G0_NC: u32 = 527;
+pub const R_AARCH64_TLSLD_ADD_DTPREL_HI12: u32 = 528;
+pub const R_AARCH64_TLSLD_ADD_DTPREL_LO12: u32 = 529;
+pub const R_AARCH64_TLSLD_ADD_DTPREL_LO12_NC: u32 = 530;
+pub const R_AARCH64_TLSLD_LDST8_DTPREL_LO12: u32 = 531;
+pub const R_AARCH64_TLSLD_LDST8_DTPREL_LO12_NC: u32 = 532;
+pub const R_AARCH64_TLSLD_LDST16_DTPREL_LO12: u32 = 533;
+pub const R_AARCH64_TLSLD_LDST16_DTPREL_LO12_NC: u32 = 534;
+pub const R_AARCH64_TLSLD_LDST32_DTPREL_LO12: u32 = 535;
+pub const R_AARCH64_TLSLD_LDST32_DTPREL_LO12_NC: u32 = 536;
+pub const R_AARCH64_TLSLD_LDST64_DTPREL_LO12: u32 = 537;
+pub const R_AARCH64_TLSLD_LDST64_DTPREL_LO12_NC: u32 = 538;
+pub const R_AARCH64_TLSIE_MOVW_GOTTPREL_G1: u32 = 539;
+pub const R_AARCH64_TLSIE_MOVW_GOTTPREL_G0_NC: u32 = 540;
+pub const R_AARCH64_TLSIE_ADR_GOTTPREL_PAGE21: u32 = 541;
+pub const R_AARCH64_TLSIE_LD64_GOTTPREL_LO12_NC: u32 = 542;
+pub const R_AARCH64_TLSIE_LD_GOTTPREL_PREL19: u32 = 543;
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G2: u32 = 544;
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G1: u32 = 545;
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G1_NC: u32 = 546;
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G0: u32 = 547;
+pub const R_AARCH64_TLSLE_MOVW_TPREL_G0_NC: u32 = 548;
+pub const R_AARCH64_TLSLE_ADD_TPREL_HI12: u32 = 549;
+pub const R_AARCH64_TLSLE_ADD_TPREL_LO12: u32 = 550;
+pub const R_AARCH64_TLSLE_ADD_TPREL_LO12_NC: u32 = 551;
+pub const R_AARCH64_TLSLE_LDST8_TPREL_LO12: u32 = 552;
+pub const R_AARCH64_TLSLE_LDST8_TPREL_LO12_NC: u32 = 553;
+pub const R_AARCH64_TLSLE_LDST16_TPREL_LO12: u32 = 554;
+pub const R_AARCH64_TLSLE_LDST16_TPREL_LO12_NC: u32 = 555;
+pub const R_AARCH64_TLSLE_LDST32_TPREL_LO12: u32 = 556;
+pub const R_AARCH64_TLSLE_LDST32_TPREL_LO12_NC: u32 = 557;
+pub const R_AARCH64_TLSLE_LDST64_TPREL_LO12: u32 = 558;
+pub const R_AARCH64_TLSLE_LDST64_TPREL_LO12_NC: u32 = 559;
+pub const R_AARCH64_TLSDESC_LD_PREL19: u32 = 560;
+pub const R_AARCH64_TLSDESC_ADR_PREL21: u32 = 561;
+pub const R_AARCH64_TLSDESC_ADR_PAGE21: u32 = 562;
+pub const R_AARCH64_TLSDESC_LD64_LO12: u32 = 563;
+pub const R_AARCH64_TLSDESC_ADD_LO12: u32 = 564;
+pub const R_AARCH64_TLSDESC_OFF_G1: u32 = 565;
+pub const R_AARCH64_TLSDESC_OFF_G0_NC: u32 = 566;
+pub const R_AARCH64_TLSDESC_LDR: u32 = 567;
+pub const R_AARCH64_TLSDESC_ADD: u32 = 568;
+pub const R_AARCH64_TLSDESC_CALL: u32 = 569;
+pub const R_AARCH64_TLSLE_LDST128_TPREL_LO12: u32 = 570;
+pub const R_AARCH64_TLSLE_LDST128_TPREL_LO12_NC: u32 = 571;
+pub const R_AARCH64_TLSLD_LDST128_DTPREL_LO12: u32 = 572;
+pub const R_AARCH64_TLSLD_LDST128_DTPREL_LO12_NC: u32 = 573;
+pub const R_AARCH64_COPY: u32 = 1024;
+pub const R_AARCH64_GLOB_DAT: u32 = 1025;
+pub const R_AARCH64_JUMP_SLOT: u32 = 1026;
+pub const R_AARCH64_RELATIVE: u32 = 1027;
+pub const R_AARCH64_TLS_DTPMOD: u32 = 1028;
+pub const R_AARCH64_TLS_DTPREL: u32 = 1029;
+pub const R_AARCH64_TLS_TPREL: u32 = 1030;
+pub const R_AARCH64_TLSDESC: u32 = 1031;
+pub const R_AARCH64_IRELATIVE: u32 = 1032;
+
+// RISC-V relocations.
+pub const R_RISCV_NONE: u32 = 0;
+pub const R_RISCV_32: u32 = 1;
+pub const R_RISCV_64: u32 = 2;
+pub const R_RISCV_RELATIVE: u32 = 3;
+pub const R_RISCV_COPY: u32 = 4;
+pub const R_RISCV_JUMP_SLOT: u32 = 5;
+pub const R_RISCV_TLS_DTPMOD32: u32 = 6;
+pub const R_RISCV_TLS_DTPMOD64: u32 = 7;
+pub const R_RISCV_TLS_DTPREL32: u32 = 8;
+pub const R_RISCV_TLS_DTPREL64: u32 = 9;
+pub const R_RISCV_TLS_TPREL32: u32 = 10;
+pub const R_RISCV_TLS_TPREL64: u32 = 11;
+pub const R_RISCV_BRANCH: u32 = 16;
+pub const R_RISCV_JAL: u32 = 17;
+pub const R_RISCV_CALL: u32 = 18;
+pub const R_RISCV_CALL_PLT: u32 = 19;
+pub const R_RISCV_GOT_HI20: u32 = 20;
+pub const R_RISCV_TLS_GOT_HI20: u32 = 21;
+pub const R_RISCV_TLS_GD_HI20: u32 = 22;
+pub const R_RISCV_PCREL_HI20: u32 = 23;
+pub const R_RISCV_PCREL_LO12_I: u32 = 24;
+pub const R_RISCV_PCREL_LO12_S: u32 = 25;
+pub const R_RISCV_HI20: u32 = 26;
+pub const R_RISCV_LO12_I: u32 = 27;
+pub const R_RISCV_LO12_S: u32 = 28;
+pub const R_RISCV_TPREL_HI20: u32 = 29;
+pub const R_RISCV_TPREL_LO12_I: u32 = 30;
+pub const R_RISCV_TPREL_LO12_S: u32 = 31;
+pub const R_RISCV_TPREL_ADD: u32 = 32;
+pub const R_RISCV_ADD8: u32 = 33;
+pub const R_RISCV_ADD16: u32 = 34;
+pub const R_RISCV_ADD32: u32 = 35;
+pub const R_RISCV_ADD64: u32 = 36;
+pub const R_RISCV_SUB8: u32 = 37;
+pub const R_RISCV_SUB16: u32 = 38;
+pub const R_RISCV_SUB32: u32 = 39;
+pub const R_RISCV_SUB64: u32 = 40;
+pub const R_RISCV_GNU_VTINHERIT: u32 = 41;
+pub const R_RISCV_GNU_VTENTRY: u32 = 42;
+pub const R_RISCV_ALIGN: u32 = 43;
+pub const R_RISCV_RVC_BRANCH: u32 = 44;
+pub const R_RISCV_RVC_JUMP: u32 = 45;
+pub const R_RISCV_RVC_LUI: u32 = 46;
+pub const R_RISCV_GPREL_I: u32 = 47;
+pub const R_RISCV_GPREL_S: u32 = 48;
+pub const R_RISCV_TPREL_I: u32 = 49;
+pub const R_RISCV_TPREL_S: u32 = 50;
+pub const R_RISCV_RELAX: u32 = 51;
+pub const R_RISCV_SUB6: u32 = 52;
+pub const R_RISCV_SET6: u32 = 53;
+pub const R_RISCV_SET8: u32 = 54;
+pub const R_RISCV_SET16: u32 = 55;
+pub const R_RISCV_SET32: u32 = 56;
+pub const R_RISCV_32_PCREL: u32 = 57;
+pub const R_RISCV_IRELATIVE: u32 = 58;
+
+f! {
+    pub const safe fn ELF32_ST_BIND(val: c_uchar) -> c_uchar {
+        val >> 4
+    }
+
+    pub const safe fn ELF32_ST_TYPE(val: c_uchar) -> c_uchar {
+        val & 0xf
+    }
+
+    pub const safe fn ELF32_ST_INFO(bind: c_uchar, type_: c_uchar) -> c_uchar {
+        (bind << 4) + (type_ & 0xf)
+    }
+
+    pub const safe fn ELF64_ST_BIND(val: c_uchar) -> c_uchar {
+        ELF32_ST_BIND(val)
+    }
+
+    pub const safe fn ELF64_ST_TYPE(val: c_uchar) -> c_uchar {
+        ELF32_ST_TYPE(val)
+    }
+
+    pub const safe fn ELF64_ST_INFO(bind: c_uchar, type_: c_uchar) -> c_uchar {
+        ELF32_ST_INFO(bind, type_)
+    }
+
+    pub const safe fn ELF32_ST_VISIBILITY(o: c_uchar) -> c_uchar {
+        o & 0x03
+    }
+
+    pub const safe fn ELF64_ST_VISIBILITY(o: c_uchar) -> c_uchar {
+        ELF32_ST_VISIBILITY(o)
+    }
+}
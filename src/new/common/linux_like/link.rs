@@ -0,0 +1,60 @@
+//! Header: `link.h`
+//!
+//! The debugger interface of the run-time dynamic linker. glibc, musl and bionic agree on the
+//! layout of these structures; glibc-specific extensions are defined with the rest of the glibc
+//! API.
+
+use crate::prelude::*;
+
+c_enum! {
+    // Values of `r_debug::r_state`.
+    #[repr(c_int)]
+    pub enum #anon {
+        pub RT_CONSISTENT = 0,
+        pub RT_ADD,
+        pub RT_DELETE,
+    }
+}
+
+s! {
+    /// A loaded shared object.
+    ///
+    /// `l_next` and `l_prev` chain all objects known to the dynamic linker. The C libraries keep
+    /// further private fields after these, so this structure must only be used through pointers
+    /// handed out by the dynamic linker.
+    pub struct link_map {
+        #[cfg(target_pointer_width = "64")]
+        pub l_addr: crate::Elf64_Addr,
+        #[cfg(target_pointer_width = "32")]
+        pub l_addr: crate::Elf32_Addr,
+
+        pub l_name: *mut c_char,
+
+        #[cfg(target_pointer_width = "64")]
+        pub l_ld: *mut crate::Elf64_Dyn,
+        #[cfg(target_pointer_width = "32")]
+        pub l_ld: *mut crate::Elf32_Dyn,
+
+        pub l_next: *mut link_map,
+        pub l_prev: *mut link_map,
+    }
+
+    /// The rendezvous structure shared between the dynamic linker and a debugger.
+    pub struct r_debug {
+        pub r_version: c_int,
+        pub r_map: *mut link_map,
+
+        #[cfg(target_pointer_width = "64")]
+        pub r_brk: crate::Elf64_Addr,
+        #[cfg(target_pointer_width = "32")]
+        pub r_brk: crate::Elf32_Addr,
+
+        /// One of [`RT_CONSISTENT`], [`RT_ADD`] or [`RT_DELETE`].
+        pub r_state: c_int,
+
+        #[cfg(target_pointer_width = "64")]
+        pub r_ldbase: crate::Elf64_Addr,
+        #[cfg(target_pointer_width = "32")]
+        pub r_ldbase: crate::Elf32_Addr,
+    }
+}
@@ -7,3 +7,19 @@
     target_os = "linux"
 ))]
 pub(crate) mod pthread;
+
+/// Directory: `bluetooth/`
+#[cfg(target_os = "linux")]
+pub(crate) mod bluetooth;
+
+#[cfg(target_os = "linux")]
+pub(crate) mod elf;
+
+#[cfg(any(target_os = "android", target_os = "linux"))]
+pub(crate) mod link;
+
+/// Directory: `scsi/`
+#[cfg(target_os = "linux")]
+pub(crate) mod scsi {
+    pub(crate) mod sg;
+}
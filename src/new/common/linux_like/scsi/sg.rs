@@ -0,0 +1,124 @@
+//! Header: `scsi/sg.h`
+//!
+//! This header is provided by the C library rather than the kernel's UAPI, but glibc, musl and
+//! uClibc all ship the same definitions.
+
+use crate::prelude::*;
+use crate::Ioctl;
+
+s! {
+    pub struct sg_iovec {
+        pub iov_base: *mut c_void,
+        pub iov_len: size_t,
+    }
+
+    pub struct sg_io_hdr {
+        pub interface_id: c_int,
+        pub dxfer_direction: c_int,
+        pub cmd_len: c_uchar,
+        pub mx_sb_len: c_uchar,
+        pub iovec_count: c_ushort,
+        pub dxfer_len: c_uint,
+        pub dxferp: *mut c_void,
+        pub cmdp: *mut c_uchar,
+        pub sbp: *mut c_uchar,
+        pub timeout: c_uint,
+        pub flags: c_uint,
+        pub pack_id: c_int,
+        pub usr_ptr: *mut c_void,
+        pub status: c_uchar,
+        pub masked_status: c_uchar,
+        pub msg_status: c_uchar,
+        pub sb_len_wr: c_uchar,
+        pub host_status: c_ushort,
+        pub driver_status: c_ushort,
+        pub resid: c_int,
+        pub duration: c_uint,
+        pub info: c_uint,
+    }
+
+    pub struct sg_scsi_id {
+        pub host_no: c_int,
+        pub channel: c_int,
+        pub scsi_id: c_int,
+        pub lun: c_int,
+        pub scsi_type: c_int,
+        pub h_cmd_per_lun: c_short,
+        pub d_queue_depth: c_short,
+        unused: Padding<[c_int; 2]>,
+    }
+
+    pub struct sg_req_info {
+        pub req_state: c_char,
+        pub orphan: c_char,
+        pub sg_io_owned: c_char,
+        pub problem: c_char,
+        pub pack_id: c_int,
+        pub usr_ptr: *mut c_void,
+        pub duration: c_uint,
+        unused: Padding<c_int>,
+    }
+}
+
+pub type sg_iovec_t = sg_iovec;
+pub type sg_io_hdr_t = sg_io_hdr;
+pub type sg_req_info_t = sg_req_info;
+
+pub const SG_DXFER_NONE: c_int = -1;
+pub const SG_DXFER_TO_DEV: c_int = -2;
+pub const SG_DXFER_FROM_DEV: c_int = -3;
+pub const SG_DXFER_TO_FROM_DEV: c_int = -4;
+
+pub const SG_FLAG_DIRECT_IO: c_uint = 1;
+pub const SG_FLAG_LUN_INHIBIT: c_uint = 2;
+pub const SG_FLAG_NO_DXFER: c_uint = 0x10000;
+
+pub const SG_INFO_OK_MASK: c_uint = 0x1;
+pub const SG_INFO_OK: c_uint = 0x0;
+pub const SG_INFO_CHECK: c_uint = 0x1;
+
+pub const SG_INFO_DIRECT_IO_MASK: c_uint = 0x6;
+pub const SG_INFO_INDIRECT_IO: c_uint = 0x0;
+pub const SG_INFO_DIRECT_IO: c_uint = 0x2;
+pub const SG_INFO_MIXED_IO: c_uint = 0x4;
+
+pub const SG_EMULATED_HOST: Ioctl = 0x2203;
+pub const SG_SET_TRANSFORM: Ioctl = 0x2204;
+pub const SG_GET_TRANSFORM: Ioctl = 0x2205;
+pub const SG_SET_RESERVED_SIZE: Ioctl = 0x2275;
+pub const SG_GET_RESERVED_SIZE: Ioctl = 0x2272;
+pub const SG_GET_SCSI_ID: Ioctl = 0x2276;
+pub const SG_SET_FORCE_LOW_DMA: Ioctl = 0x2279;
+pub const SG_GET_LOW_DMA: Ioctl = 0x227a;
+pub const SG_SET_FORCE_PACK_ID: Ioctl = 0x227b;
+pub const SG_GET_PACK_ID: Ioctl = 0x227c;
+pub const SG_GET_NUM_WAITING: Ioctl = 0x227d;
+pub const SG_GET_SG_TABLESIZE: Ioctl = 0x227F;
+pub const SG_GET_VERSION_NUM: Ioctl = 0x2282;
+pub const SG_SCSI_RESET: Ioctl = 0x2284;
+pub const SG_IO: Ioctl = 0x2285;
+pub const SG_GET_REQUEST_TABLE: Ioctl = 0x2286;
+pub const SG_SET_KEEP_ORPHAN: Ioctl = 0x2287;
+pub const SG_GET_KEEP_ORPHAN: Ioctl = 0x2288;
+
+pub const SG_SET_TIMEOUT: Ioctl = 0x2201;
+pub const SG_GET_TIMEOUT: Ioctl = 0x2202;
+pub const SG_GET_COMMAND_Q: Ioctl = 0x2270;
+pub const SG_SET_COMMAND_Q: Ioctl = 0x2271;
+pub const SG_SET_DEBUG: Ioctl = 0x227e;
+pub const SG_NEXT_CMD_LEN: Ioctl = 0x2283;
+
+pub const SG_SCSI_RESET_NOTHING: c_int = 0;
+pub const SG_SCSI_RESET_DEVICE: c_int = 1;
+pub const SG_SCSI_RESET_BUS: c_int = 2;
+pub const SG_SCSI_RESET_HOST: c_int = 3;
+
+pub const SG_SCATTER_SZ: c_int = 8 * 4096;
+pub const SG_DEFAULT_RETRIES: c_int = 1;
+pub const SG_DEF_FORCE_LOW_DMA: c_int = 0;
+pub const SG_DEF_FORCE_PACK_ID: c_int = 0;
+pub const SG_DEF_KEEP_ORPHAN: c_int = 0;
+pub const SG_DEF_RESERVED_SIZE: c_int = SG_SCATTER_SZ;
+pub const SG_MAX_QUEUE: c_int = 16;
+pub const SG_BIG_BUFF: c_int = SG_DEF_RESERVED_SIZE;
+pub const SG_MAX_SENSE: c_int = 16;
@@ -0,0 +1,4 @@
+//! Header: `misc/sys/auxv.h`
+
+#[cfg(target_arch = "s390x")]
+pub use super::sysdeps::unix::linux::bits::hwcap::*;
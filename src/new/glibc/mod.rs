@@ -21,6 +21,8 @@ mod posix {
     pub(crate) mod unistd;
 }
 
+#[cfg(target_os = "linux")]
+pub(crate) mod auxv;
 #[cfg(target_os = "linux")]
 pub(crate) mod signal;
 
@@ -2,6 +2,8 @@
 
 pub use super::bits::signum_generic::*;
 pub use super::sysdeps::unix::linux::bits::sigaction::*;
+#[cfg(target_arch = "x86_64")]
+pub use super::sysdeps::unix::linux::bits::sigcontext::*;
 use crate::prelude::*;
 
 extern "C" {
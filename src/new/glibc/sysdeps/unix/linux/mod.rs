@@ -4,6 +4,9 @@
 
 /// Directory: `sysdeps/unix/sysv/linux/bits`
 pub(crate) mod bits {
+    #[cfg(target_arch = "s390x")]
+    #[path = "../s390/bits/hwcap.rs"]
+    pub(crate) mod hwcap;
     #[cfg_attr(
         any(
             target_arch = "mips",
@@ -19,6 +22,9 @@ pub(crate) mod bits {
         path = "../sparc/bits/sigaction.rs"
     )]
     pub(crate) mod sigaction;
+    #[cfg(target_arch = "x86_64")]
+    #[path = "../x86/bits/sigcontext.rs"]
+    pub(crate) mod sigcontext;
     #[cfg_attr(
         any(
             target_arch = "mips",
@@ -0,0 +1,33 @@
+//! Header: `sysdeps/unix/sysv/linux/s390/bits/hwcap.h`
+//!
+//! Bits reported in `AT_HWCAP`. Unlike most architectures, s390 does not export these from the
+//! kernel UAPI headers.
+
+use crate::prelude::*;
+
+pub const HWCAP_S390_ESAN3: c_ulong = 1;
+pub const HWCAP_S390_ZARCH: c_ulong = 2;
+pub const HWCAP_S390_STFLE: c_ulong = 4;
+pub const HWCAP_S390_MSA: c_ulong = 8;
+pub const HWCAP_S390_LDISP: c_ulong = 16;
+pub const HWCAP_S390_EIMM: c_ulong = 32;
+pub const HWCAP_S390_DFP: c_ulong = 64;
+pub const HWCAP_S390_HPAGE: c_ulong = 128;
+pub const HWCAP_S390_ETF3EH: c_ulong = 256;
+pub const HWCAP_S390_HIGH_GPRS: c_ulong = 512;
+pub const HWCAP_S390_TE: c_ulong = 1024;
+pub const HWCAP_S390_VX: c_ulong = 2048;
+pub const HWCAP_S390_VXRS: c_ulong = HWCAP_S390_VX;
+pub const HWCAP_S390_VXD: c_ulong = 4096;
+pub const HWCAP_S390_VXRS_BCD: c_ulong = HWCAP_S390_VXD;
+pub const HWCAP_S390_VXE: c_ulong = 8192;
+pub const HWCAP_S390_VXRS_EXT: c_ulong = HWCAP_S390_VXE;
+pub const HWCAP_S390_GS: c_ulong = 16384;
+pub const HWCAP_S390_VXRS_EXT2: c_ulong = 32768;
+pub const HWCAP_S390_VXRS_PDE: c_ulong = 65536;
+pub const HWCAP_S390_SORT: c_ulong = 131072;
+pub const HWCAP_S390_DFLT: c_ulong = 262144;
+pub const HWCAP_S390_VXRS_PDE2: c_ulong = 524288;
+pub const HWCAP_S390_NNPA: c_ulong = 1048576;
+pub const HWCAP_S390_PCI_MIO: c_ulong = 2097152;
+pub const HWCAP_S390_SIE: c_ulong = 4194304;
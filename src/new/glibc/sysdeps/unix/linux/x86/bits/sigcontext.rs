@@ -0,0 +1,72 @@
+//! Header: `sysdeps/unix/sysv/linux/x86/bits/sigcontext.h`
+//!
+//! Only the x86_64 XSAVE layout is defined here; the legacy `sigcontext` is described by
+//! `mcontext_t`.
+
+use crate::prelude::*;
+
+pub const FP_XSTATE_MAGIC1: u32 = 0x46505853;
+pub const FP_XSTATE_MAGIC2: u32 = 0x46505845;
+pub const FP_XSTATE_MAGIC2_SIZE: usize = size_of::<u32>();
+
+s! {
+    /// Software-reserved bytes at the end of [`_fpstate`], valid when `magic1` is
+    /// [`FP_XSTATE_MAGIC1`].
+    pub struct _fpx_sw_bytes {
+        pub magic1: u32,
+        /// Size of the whole extended state, including [`FP_XSTATE_MAGIC2`].
+        pub extended_size: u32,
+        pub xstate_bv: u64,
+        /// Size of the [`_xstate`] area.
+        pub xstate_size: u32,
+        __glibc_reserved1: Padding<[u32; 7]>,
+    }
+
+    pub struct _fpreg {
+        pub significand: [u16; 4],
+        pub exponent: u16,
+    }
+
+    pub struct _fpxreg {
+        pub significand: [u16; 4],
+        pub exponent: u16,
+        __glibc_reserved1: Padding<[u16; 3]>,
+    }
+
+    pub struct _xmmreg {
+        pub element: [u32; 4],
+    }
+
+    /// The FPU environment in the 64-bit FXSAVE layout.
+    pub struct _fpstate {
+        pub cwd: u16,
+        pub swd: u16,
+        pub ftw: u16,
+        pub fop: u16,
+        pub rip: u64,
+        pub rdp: u64,
+        pub mxcsr: u32,
+        pub mxcr_mask: u32,
+        pub _st: [_fpxreg; 8],
+        pub _xmm: [_xmmreg; 16],
+        /// The last 12 words hold an [`_fpx_sw_bytes`] in signal frames.
+        pub __glibc_reserved1: [u32; 24],
+    }
+
+    pub struct _xsave_hdr {
+        pub xstate_bv: u64,
+        __glibc_reserved1: Padding<[u64; 2]>,
+        __glibc_reserved2: Padding<[u64; 5]>,
+    }
+
+    pub struct _ymmh_state {
+        pub ymmh_space: [u32; 64],
+    }
+
+    /// The XSAVE area pointed to by `mcontext_t::fpregs` when [`_fpx_sw_bytes`] is valid.
+    pub struct _xstate {
+        pub fpstate: _fpstate,
+        pub xstate_hdr: _xsave_hdr,
+        pub ymmh: _ymmh_state,
+    }
+}
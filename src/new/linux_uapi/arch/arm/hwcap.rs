@@ -0,0 +1,43 @@
+//! Header: `arch/arm/include/uapi/asm/hwcap.h`
+//!
+//! Bits of the `AT_HWCAP` and `AT_HWCAP2` auxiliary vector entries.
+
+use crate::prelude::*;
+
+pub const HWCAP_SWP: c_ulong = 1 << 0;
+pub const HWCAP_HALF: c_ulong = 1 << 1;
+pub const HWCAP_THUMB: c_ulong = 1 << 2;
+pub const HWCAP_26BIT: c_ulong = 1 << 3;
+pub const HWCAP_FAST_MULT: c_ulong = 1 << 4;
+pub const HWCAP_FPA: c_ulong = 1 << 5;
+pub const HWCAP_VFP: c_ulong = 1 << 6;
+pub const HWCAP_EDSP: c_ulong = 1 << 7;
+pub const HWCAP_JAVA: c_ulong = 1 << 8;
+pub const HWCAP_IWMMXT: c_ulong = 1 << 9;
+pub const HWCAP_CRUNCH: c_ulong = 1 << 10;
+pub const HWCAP_THUMBEE: c_ulong = 1 << 11;
+pub const HWCAP_NEON: c_ulong = 1 << 12;
+pub const HWCAP_VFPv3: c_ulong = 1 << 13;
+pub const HWCAP_VFPv3D16: c_ulong = 1 << 14;
+pub const HWCAP_TLS: c_ulong = 1 << 15;
+pub const HWCAP_VFPv4: c_ulong = 1 << 16;
+pub const HWCAP_IDIVA: c_ulong = 1 << 17;
+pub const HWCAP_IDIVT: c_ulong = 1 << 18;
+pub const HWCAP_VFPD32: c_ulong = 1 << 19;
+pub const HWCAP_IDIV: c_ulong = HWCAP_IDIVA | HWCAP_IDIVT;
+pub const HWCAP_LPAE: c_ulong = 1 << 20;
+pub const HWCAP_EVTSTRM: c_ulong = 1 << 21;
+pub const HWCAP_FPHP: c_ulong = 1 << 22;
+pub const HWCAP_ASIMDHP: c_ulong = 1 << 23;
+pub const HWCAP_ASIMDDP: c_ulong = 1 << 24;
+pub const HWCAP_ASIMDFHM: c_ulong = 1 << 25;
+pub const HWCAP_ASIMDBF16: c_ulong = 1 << 26;
+pub const HWCAP_I8MM: c_ulong = 1 << 27;
+
+pub const HWCAP2_AES: c_ulong = 1 << 0;
+pub const HWCAP2_PMULL: c_ulong = 1 << 1;
+pub const HWCAP2_SHA1: c_ulong = 1 << 2;
+pub const HWCAP2_SHA2: c_ulong = 1 << 3;
+pub const HWCAP2_CRC32: c_ulong = 1 << 4;
+pub const HWCAP2_SB: c_ulong = 1 << 5;
+pub const HWCAP2_SSBS: c_ulong = 1 << 6;
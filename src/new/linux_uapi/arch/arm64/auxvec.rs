@@ -0,0 +1,7 @@
+//! Header: `arch/arm64/include/uapi/asm/auxvec.h`
+
+use crate::prelude::*;
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const AT_VECTOR_SIZE_ARCH: c_ulong = 2;
@@ -0,0 +1,133 @@
+//! Header: `arch/arm64/include/uapi/asm/hwcap.h`
+//!
+//! Bits of the `AT_HWCAP`, `AT_HWCAP2` and `AT_HWCAP3` auxiliary vector entries.
+
+use crate::prelude::*;
+
+pub const HWCAP_FP: c_ulong = 1 << 0;
+pub const HWCAP_ASIMD: c_ulong = 1 << 1;
+pub const HWCAP_EVTSTRM: c_ulong = 1 << 2;
+pub const HWCAP_AES: c_ulong = 1 << 3;
+pub const HWCAP_PMULL: c_ulong = 1 << 4;
+pub const HWCAP_SHA1: c_ulong = 1 << 5;
+pub const HWCAP_SHA2: c_ulong = 1 << 6;
+pub const HWCAP_CRC32: c_ulong = 1 << 7;
+pub const HWCAP_ATOMICS: c_ulong = 1 << 8;
+pub const HWCAP_FPHP: c_ulong = 1 << 9;
+pub const HWCAP_ASIMDHP: c_ulong = 1 << 10;
+pub const HWCAP_CPUID: c_ulong = 1 << 11;
+pub const HWCAP_ASIMDRDM: c_ulong = 1 << 12;
+pub const HWCAP_JSCVT: c_ulong = 1 << 13;
+pub const HWCAP_FCMA: c_ulong = 1 << 14;
+pub const HWCAP_LRCPC: c_ulong = 1 << 15;
+pub const HWCAP_DCPOP: c_ulong = 1 << 16;
+pub const HWCAP_SHA3: c_ulong = 1 << 17;
+pub const HWCAP_SM3: c_ulong = 1 << 18;
+pub const HWCAP_SM4: c_ulong = 1 << 19;
+pub const HWCAP_ASIMDDP: c_ulong = 1 << 20;
+pub const HWCAP_SHA512: c_ulong = 1 << 21;
+pub const HWCAP_SVE: c_ulong = 1 << 22;
+pub const HWCAP_ASIMDFHM: c_ulong = 1 << 23;
+pub const HWCAP_DIT: c_ulong = 1 << 24;
+pub const HWCAP_USCAT: c_ulong = 1 << 25;
+pub const HWCAP_ILRCPC: c_ulong = 1 << 26;
+pub const HWCAP_FLAGM: c_ulong = 1 << 27;
+pub const HWCAP_SSBS: c_ulong = 1 << 28;
+pub const HWCAP_SB: c_ulong = 1 << 29;
+pub const HWCAP_PACA: c_ulong = 1 << 30;
+pub const HWCAP_PACG: c_ulong = 1 << 31;
+
+// The upper half of `AT_HWCAP` and `AT_HWCAP2` only exists on LP64.
+cfg_if! {
+    if #[cfg(target_pointer_width = "64")] {
+        pub const HWCAP_GCS: c_ulong = 1 << 32;
+        pub const HWCAP_CMPBR: c_ulong = 1 << 33;
+        pub const HWCAP_FPRCVT: c_ulong = 1 << 34;
+        pub const HWCAP_F8MM8: c_ulong = 1 << 35;
+        pub const HWCAP_F8MM4: c_ulong = 1 << 36;
+        pub const HWCAP_SVE_F16MM: c_ulong = 1 << 37;
+        pub const HWCAP_SVE_ELTPERM: c_ulong = 1 << 38;
+        pub const HWCAP_SVE_AES2: c_ulong = 1 << 39;
+        pub const HWCAP_SVE_BFSCALE: c_ulong = 1 << 40;
+        pub const HWCAP_SVE2P2: c_ulong = 1 << 41;
+        pub const HWCAP_SME2P2: c_ulong = 1 << 42;
+        pub const HWCAP_SME_SBITPERM: c_ulong = 1 << 43;
+        pub const HWCAP_SME_AES: c_ulong = 1 << 44;
+        pub const HWCAP_SME_SFEXPA: c_ulong = 1 << 45;
+        pub const HWCAP_SME_STMOP: c_ulong = 1 << 46;
+        pub const HWCAP_SME_SMOP4: c_ulong = 1 << 47;
+    }
+}
+
+pub const HWCAP2_DCPODP: c_ulong = 1 << 0;
+pub const HWCAP2_SVE2: c_ulong = 1 << 1;
+pub const HWCAP2_SVEAES: c_ulong = 1 << 2;
+pub const HWCAP2_SVEPMULL: c_ulong = 1 << 3;
+pub const HWCAP2_SVEBITPERM: c_ulong = 1 << 4;
+pub const HWCAP2_SVESHA3: c_ulong = 1 << 5;
+pub const HWCAP2_SVESM4: c_ulong = 1 << 6;
+pub const HWCAP2_FLAGM2: c_ulong = 1 << 7;
+pub const HWCAP2_FRINT: c_ulong = 1 << 8;
+pub const HWCAP2_SVEI8MM: c_ulong = 1 << 9;
+pub const HWCAP2_SVEF32MM: c_ulong = 1 << 10;
+pub const HWCAP2_SVEF64MM: c_ulong = 1 << 11;
+pub const HWCAP2_SVEBF16: c_ulong = 1 << 12;
+pub const HWCAP2_I8MM: c_ulong = 1 << 13;
+pub const HWCAP2_BF16: c_ulong = 1 << 14;
+pub const HWCAP2_DGH: c_ulong = 1 << 15;
+pub const HWCAP2_RNG: c_ulong = 1 << 16;
+pub const HWCAP2_BTI: c_ulong = 1 << 17;
+pub const HWCAP2_MTE: c_ulong = 1 << 18;
+pub const HWCAP2_ECV: c_ulong = 1 << 19;
+pub const HWCAP2_AFP: c_ulong = 1 << 20;
+pub const HWCAP2_RPRES: c_ulong = 1 << 21;
+pub const HWCAP2_MTE3: c_ulong = 1 << 22;
+pub const HWCAP2_SME: c_ulong = 1 << 23;
+pub const HWCAP2_SME_I16I64: c_ulong = 1 << 24;
+pub const HWCAP2_SME_F64F64: c_ulong = 1 << 25;
+pub const HWCAP2_SME_I8I32: c_ulong = 1 << 26;
+pub const HWCAP2_SME_F16F32: c_ulong = 1 << 27;
+pub const HWCAP2_SME_B16F32: c_ulong = 1 << 28;
+pub const HWCAP2_SME_F32F32: c_ulong = 1 << 29;
+pub const HWCAP2_SME_FA64: c_ulong = 1 << 30;
+pub const HWCAP2_WFXT: c_ulong = 1 << 31;
+
+cfg_if! {
+    if #[cfg(target_pointer_width = "64")] {
+        pub const HWCAP2_EBF16: c_ulong = 1 << 32;
+        pub const HWCAP2_SVE_EBF16: c_ulong = 1 << 33;
+        pub const HWCAP2_CSSC: c_ulong = 1 << 34;
+        pub const HWCAP2_RPRFM: c_ulong = 1 << 35;
+        pub const HWCAP2_SVE2P1: c_ulong = 1 << 36;
+        pub const HWCAP2_SME2: c_ulong = 1 << 37;
+        pub const HWCAP2_SME2P1: c_ulong = 1 << 38;
+        pub const HWCAP2_SME_I16I32: c_ulong = 1 << 39;
+        pub const HWCAP2_SME_BI32I32: c_ulong = 1 << 40;
+        pub const HWCAP2_SME_B16B16: c_ulong = 1 << 41;
+        pub const HWCAP2_SME_F16F16: c_ulong = 1 << 42;
+        pub const HWCAP2_MOPS: c_ulong = 1 << 43;
+        pub const HWCAP2_HBC: c_ulong = 1 << 44;
+        pub const HWCAP2_SVE_B16B16: c_ulong = 1 << 45;
+        pub const HWCAP2_LRCPC3: c_ulong = 1 << 46;
+        pub const HWCAP2_LSE128: c_ulong = 1 << 47;
+        pub const HWCAP2_FPMR: c_ulong = 1 << 48;
+        pub const HWCAP2_LUT: c_ulong = 1 << 49;
+        pub const HWCAP2_FAMINMAX: c_ulong = 1 << 50;
+        pub const HWCAP2_F8CVT: c_ulong = 1 << 51;
+        pub const HWCAP2_F8FMA: c_ulong = 1 << 52;
+        pub const HWCAP2_F8DP4: c_ulong = 1 << 53;
+        pub const HWCAP2_F8DP2: c_ulong = 1 << 54;
+        pub const HWCAP2_F8E4M3: c_ulong = 1 << 55;
+        pub const HWCAP2_F8E5M2: c_ulong = 1 << 56;
+        pub const HWCAP2_SME_LUTV2: c_ulong = 1 << 57;
+        pub const HWCAP2_SME_F8F16: c_ulong = 1 << 58;
+        pub const HWCAP2_SME_F8F32: c_ulong = 1 << 59;
+        pub const HWCAP2_SME_SF8FMA: c_ulong = 1 << 60;
+        pub const HWCAP2_SME_SF8DP4: c_ulong = 1 << 61;
+        pub const HWCAP2_SME_SF8DP2: c_ulong = 1 << 62;
+        pub const HWCAP2_POE: c_ulong = 1 << 63;
+    }
+}
+
+pub const HWCAP3_MTE_FAR: c_ulong = 1 << 0;
+pub const HWCAP3_MTE_STORE_ONLY: c_ulong = 1 << 1;
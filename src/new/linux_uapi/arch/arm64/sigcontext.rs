@@ -0,0 +1,181 @@
+//! Header: `arch/arm64/include/uapi/asm/sigcontext.h`
+//!
+//! The `__reserved` area of the signal frame's `mcontext_t` holds a sequence of records, each
+//! starting with an [`_aarch64_ctx`] header and terminated by a header with a zero `magic`.
+
+use crate::prelude::*;
+use crate::{
+    __u16,
+    __u32,
+    __u64,
+};
+
+s! {
+    pub struct _aarch64_ctx {
+        pub magic: __u32,
+        pub size: __u32,
+    }
+
+    pub struct fpsimd_context {
+        pub head: _aarch64_ctx,
+        pub fpsr: __u32,
+        pub fpcr: __u32,
+        pub vregs: [u128; 32],
+    }
+
+    pub struct esr_context {
+        pub head: _aarch64_ctx,
+        pub esr: __u64,
+    }
+
+    /// Describes additional records stored outside of the `__reserved` area.
+    pub struct extra_context {
+        pub head: _aarch64_ctx,
+        /// 16-byte aligned pointer to the extra space.
+        pub datap: __u64,
+        /// Size in bytes of the extra space.
+        pub size: __u32,
+        __reserved: Padding<[__u32; 3]>,
+    }
+
+    /// Followed by the SVE registers; see [`SVE_SIG_REGS_OFFSET`] and the related functions.
+    pub struct sve_context {
+        pub head: _aarch64_ctx,
+        pub vl: __u16,
+        pub flags: __u16,
+        __reserved: Padding<[__u16; 2]>,
+    }
+
+    pub struct tpidr2_context {
+        pub head: _aarch64_ctx,
+        pub tpidr2: __u64,
+    }
+
+    /// Followed by the ZA array when `head.size` is larger than this structure; see
+    /// [`ZA_SIG_REGS_OFFSET`] and the related functions.
+    pub struct za_context {
+        pub head: _aarch64_ctx,
+        pub vl: __u16,
+        __reserved: Padding<[__u16; 3]>,
+    }
+
+    /// Followed by `nregs` ZT registers; see [`ZT_SIG_REGS_OFFSET`].
+    pub struct zt_context {
+        pub head: _aarch64_ctx,
+        pub nregs: __u16,
+        __reserved: Padding<[__u16; 3]>,
+    }
+}
+
+pub const FPSIMD_MAGIC: __u32 = 0x46508001;
+pub const ESR_MAGIC: __u32 = 0x45535201;
+pub const EXTRA_MAGIC: __u32 = 0x45585401;
+pub const SVE_MAGIC: __u32 = 0x53564501;
+pub const TPIDR2_MAGIC: __u32 = 0x54504902;
+pub const ZA_MAGIC: __u32 = 0x54366345;
+pub const ZT_MAGIC: __u32 = 0x5a544e01;
+
+/// The SVE context describes streaming mode.
+pub const SVE_SIG_FLAG_SM: __u16 = 0x1;
+
+// Header: `arch/arm64/include/uapi/asm/sve_context.h`
+pub const SVE_VQ_BYTES: __u32 = 16;
+pub const SVE_VQ_MIN: __u32 = 1;
+pub const SVE_VQ_MAX: __u32 = 512;
+pub const SVE_VL_MIN: __u32 = SVE_VQ_MIN * SVE_VQ_BYTES;
+pub const SVE_VL_MAX: __u32 = SVE_VQ_MAX * SVE_VQ_BYTES;
+pub const SVE_NUM_ZREGS: __u32 = 32;
+pub const SVE_NUM_PREGS: __u32 = 16;
+
+/// Offset of the SVE registers from the start of the [`sve_context`].
+pub const SVE_SIG_REGS_OFFSET: __u32 =
+    (size_of::<sve_context>() as __u32 + (SVE_VQ_BYTES - 1)) / SVE_VQ_BYTES * SVE_VQ_BYTES;
+pub const SVE_SIG_ZREGS_OFFSET: __u32 = SVE_SIG_REGS_OFFSET;
+
+/// Offset of the ZA array from the start of the [`za_context`].
+pub const ZA_SIG_REGS_OFFSET: __u32 =
+    (size_of::<za_context>() as __u32 + (SVE_VQ_BYTES - 1)) / SVE_VQ_BYTES * SVE_VQ_BYTES;
+
+pub const ZT_SIG_REG_SIZE: __u32 = 512;
+pub const ZT_SIG_REG_BYTES: __u32 = ZT_SIG_REG_SIZE / 8;
+/// Offset of the ZT registers from the start of the [`zt_context`].
+pub const ZT_SIG_REGS_OFFSET: __u32 = size_of::<zt_context>() as __u32;
+
+f! {
+    pub const safe fn sve_vl_valid(vl: __u32) -> bool {
+        vl % SVE_VQ_BYTES == 0 && vl >= SVE_VL_MIN && vl <= SVE_VL_MAX
+    }
+
+    pub const safe fn sve_vq_from_vl(vl: __u32) -> __u32 {
+        vl / SVE_VQ_BYTES
+    }
+
+    pub const safe fn sve_vl_from_vq(vq: __u32) -> __u32 {
+        vq * SVE_VQ_BYTES
+    }
+
+    pub const safe fn SVE_SIG_ZREG_SIZE(vq: __u32) -> __u32 {
+        vq * SVE_VQ_BYTES
+    }
+
+    pub const safe fn SVE_SIG_PREG_SIZE(vq: __u32) -> __u32 {
+        vq * (SVE_VQ_BYTES / 8)
+    }
+
+    pub const safe fn SVE_SIG_FFR_SIZE(vq: __u32) -> __u32 {
+        SVE_SIG_PREG_SIZE(vq)
+    }
+
+    pub const safe fn SVE_SIG_ZREG_OFFSET(vq: __u32, n: __u32) -> __u32 {
+        SVE_SIG_ZREGS_OFFSET + SVE_SIG_ZREG_SIZE(vq) * n
+    }
+
+    pub const safe fn SVE_SIG_ZREGS_SIZE(vq: __u32) -> __u32 {
+        SVE_SIG_ZREG_SIZE(vq) * SVE_NUM_ZREGS
+    }
+
+    pub const safe fn SVE_SIG_PREGS_OFFSET(vq: __u32) -> __u32 {
+        SVE_SIG_ZREGS_OFFSET + SVE_SIG_ZREGS_SIZE(vq)
+    }
+
+    pub const safe fn SVE_SIG_PREG_OFFSET(vq: __u32, n: __u32) -> __u32 {
+        SVE_SIG_PREGS_OFFSET(vq) + SVE_SIG_PREG_SIZE(vq) * n
+    }
+
+    pub const safe fn SVE_SIG_PREGS_SIZE(vq: __u32) -> __u32 {
+        SVE_SIG_PREG_SIZE(vq) * SVE_NUM_PREGS
+    }
+
+    pub const safe fn SVE_SIG_FFR_OFFSET(vq: __u32) -> __u32 {
+        SVE_SIG_PREGS_OFFSET(vq) + SVE_SIG_PREGS_SIZE(vq)
+    }
+
+    /// Size of the SVE registers, excluding the [`sve_context`] header.
+    pub const safe fn SVE_SIG_REGS_SIZE(vq: __u32) -> __u32 {
+        SVE_SIG_FFR_OFFSET(vq) - SVE_SIG_REGS_OFFSET + SVE_SIG_FFR_SIZE(vq)
+    }
+
+    pub const safe fn SVE_SIG_CONTEXT_SIZE(vq: __u32) -> __u32 {
+        SVE_SIG_REGS_OFFSET + SVE_SIG_REGS_SIZE(vq)
+    }
+
+    pub const safe fn ZA_SIG_REGS_SIZE(vq: __u32) -> __u32 {
+        (vq * SVE_VQ_BYTES) * (vq * SVE_VQ_BYTES)
+    }
+
+    pub const safe fn ZA_SIG_ZAV_OFFSET(vq: __u32, n: __u32) -> __u32 {
+        ZA_SIG_REGS_OFFSET + SVE_SIG_ZREG_SIZE(vq) * n
+    }
+
+    pub const safe fn ZA_SIG_CONTEXT_SIZE(vq: __u32) -> __u32 {
+        ZA_SIG_REGS_OFFSET + ZA_SIG_REGS_SIZE(vq)
+    }
+
+    pub const safe fn ZT_SIG_REGS_SIZE(n: __u32) -> __u32 {
+        ZT_SIG_REG_BYTES * n
+    }
+
+    pub const safe fn ZT_SIG_CONTEXT_SIZE(n: __u32) -> __u32 {
+        ZT_SIG_REGS_OFFSET + ZT_SIG_REGS_SIZE(n)
+    }
+}
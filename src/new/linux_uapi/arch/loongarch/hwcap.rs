@@ -0,0 +1,21 @@
+//! Header: `arch/loongarch/include/uapi/asm/hwcap.h`
+//!
+//! Bits of the `AT_HWCAP` auxiliary vector entry.
+
+use crate::prelude::*;
+
+pub const HWCAP_LOONGARCH_CPUCFG: c_ulong = 1 << 0;
+pub const HWCAP_LOONGARCH_LAM: c_ulong = 1 << 1;
+pub const HWCAP_LOONGARCH_UAL: c_ulong = 1 << 2;
+pub const HWCAP_LOONGARCH_FPU: c_ulong = 1 << 3;
+pub const HWCAP_LOONGARCH_LSX: c_ulong = 1 << 4;
+pub const HWCAP_LOONGARCH_LASX: c_ulong = 1 << 5;
+pub const HWCAP_LOONGARCH_CRC32: c_ulong = 1 << 6;
+pub const HWCAP_LOONGARCH_COMPLEX: c_ulong = 1 << 7;
+pub const HWCAP_LOONGARCH_CRYPTO: c_ulong = 1 << 8;
+pub const HWCAP_LOONGARCH_LVZ: c_ulong = 1 << 9;
+pub const HWCAP_LOONGARCH_LBT_X86: c_ulong = 1 << 10;
+pub const HWCAP_LOONGARCH_LBT_ARM: c_ulong = 1 << 11;
+pub const HWCAP_LOONGARCH_LBT_MIPS: c_ulong = 1 << 12;
+pub const HWCAP_LOONGARCH_PTW: c_ulong = 1 << 13;
+pub const HWCAP_LOONGARCH_LSPW: c_ulong = 1 << 14;
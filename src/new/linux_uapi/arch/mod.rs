@@ -0,0 +1,69 @@
+//! This directory maps to `arch/*/include/uapi` in the Linux source tree. The headers are
+//! installed as `asm/*.h`.
+
+/// Directory: `arm/include/uapi/asm`
+#[cfg(target_arch = "arm")]
+pub(crate) mod arm {
+    pub(crate) mod hwcap;
+}
+
+/// Directory: `arm64/include/uapi/asm`
+#[cfg(target_arch = "aarch64")]
+pub(crate) mod arm64 {
+    pub(crate) mod auxvec;
+    pub(crate) mod hwcap;
+    pub(crate) mod sigcontext;
+}
+
+/// Directory: `loongarch/include/uapi/asm`
+#[cfg(target_arch = "loongarch64")]
+pub(crate) mod loongarch {
+    pub(crate) mod auxvec;
+    pub(crate) mod hwcap;
+}
+
+/// Directory: `mips/include/uapi/asm`
+#[cfg(any(
+    target_arch = "mips",
+    target_arch = "mips32r6",
+    target_arch = "mips64",
+    target_arch = "mips64r6"
+))]
+pub(crate) mod mips {
+    pub(crate) mod auxvec;
+}
+
+/// Directory: `powerpc/include/uapi/asm`
+#[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+pub(crate) mod powerpc {
+    pub(crate) mod auxvec;
+    pub(crate) mod cputable;
+}
+
+/// Directory: `riscv/include/uapi/asm`
+#[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+pub(crate) mod riscv {
+    pub(crate) mod auxvec;
+    pub(crate) mod hwcap;
+    #[cfg(target_arch = "riscv64")]
+    pub(crate) mod hwprobe;
+}
+
+/// Directory: `s390/include/uapi/asm`
+#[cfg(target_arch = "s390x")]
+pub(crate) mod s390 {
+    pub(crate) mod auxvec;
+}
+
+/// Directory: `sparc/include/uapi/asm`
+#[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+pub(crate) mod sparc {
+    pub(crate) mod auxvec;
+}
+
+/// Directory: `x86/include/uapi/asm`
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) mod x86 {
+    pub(crate) mod auxvec;
+    pub(crate) mod hwcap2;
+}
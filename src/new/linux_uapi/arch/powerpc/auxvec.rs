@@ -0,0 +1,26 @@
+//! Header: `arch/powerpc/include/uapi/asm/auxvec.h`
+
+use crate::prelude::*;
+
+// Cache block sizes, in bytes.
+pub const AT_DCACHEBSIZE: c_ulong = 19;
+pub const AT_ICACHEBSIZE: c_ulong = 20;
+pub const AT_UCACHEBSIZE: c_ulong = 21;
+
+/// A special ignored type value for PPC, for glibc compatibility.
+pub const AT_IGNOREPPC: c_ulong = 22;
+
+// Cache sizes in bytes; the geometry holds the line size in the low 16 bits and the
+// associativity in the next 16 bits.
+pub const AT_L1I_CACHESIZE: c_ulong = 40;
+pub const AT_L1I_CACHEGEOMETRY: c_ulong = 41;
+pub const AT_L1D_CACHESIZE: c_ulong = 42;
+pub const AT_L1D_CACHEGEOMETRY: c_ulong = 43;
+pub const AT_L2_CACHESIZE: c_ulong = 44;
+pub const AT_L2_CACHEGEOMETRY: c_ulong = 45;
+pub const AT_L3_CACHESIZE: c_ulong = 46;
+pub const AT_L3_CACHEGEOMETRY: c_ulong = 47;
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const AT_VECTOR_SIZE_ARCH: c_ulong = 15;
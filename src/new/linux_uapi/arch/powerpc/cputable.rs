@@ -0,0 +1,50 @@
+//! Header: `arch/powerpc/include/uapi/asm/cputable.h`
+//!
+//! Bits of the `AT_HWCAP` and `AT_HWCAP2` auxiliary vector entries.
+
+use crate::prelude::*;
+
+pub const PPC_FEATURE_32: c_ulong = 0x80000000;
+pub const PPC_FEATURE_64: c_ulong = 0x40000000;
+pub const PPC_FEATURE_601_INSTR: c_ulong = 0x20000000;
+pub const PPC_FEATURE_HAS_ALTIVEC: c_ulong = 0x10000000;
+pub const PPC_FEATURE_HAS_FPU: c_ulong = 0x08000000;
+pub const PPC_FEATURE_HAS_MMU: c_ulong = 0x04000000;
+pub const PPC_FEATURE_HAS_4xxMAC: c_ulong = 0x02000000;
+pub const PPC_FEATURE_UNIFIED_CACHE: c_ulong = 0x01000000;
+pub const PPC_FEATURE_HAS_SPE: c_ulong = 0x00800000;
+pub const PPC_FEATURE_HAS_EFP_SINGLE: c_ulong = 0x00400000;
+pub const PPC_FEATURE_HAS_EFP_DOUBLE: c_ulong = 0x00200000;
+pub const PPC_FEATURE_NO_TB: c_ulong = 0x00100000;
+pub const PPC_FEATURE_POWER4: c_ulong = 0x00080000;
+pub const PPC_FEATURE_POWER5: c_ulong = 0x00040000;
+pub const PPC_FEATURE_POWER5_PLUS: c_ulong = 0x00020000;
+pub const PPC_FEATURE_CELL: c_ulong = 0x00010000;
+pub const PPC_FEATURE_BOOKE: c_ulong = 0x00008000;
+pub const PPC_FEATURE_SMT: c_ulong = 0x00004000;
+pub const PPC_FEATURE_ICACHE_SNOOP: c_ulong = 0x00002000;
+pub const PPC_FEATURE_ARCH_2_05: c_ulong = 0x00001000;
+pub const PPC_FEATURE_PA6T: c_ulong = 0x00000800;
+pub const PPC_FEATURE_HAS_DFP: c_ulong = 0x00000400;
+pub const PPC_FEATURE_POWER6_EXT: c_ulong = 0x00000200;
+pub const PPC_FEATURE_ARCH_2_06: c_ulong = 0x00000100;
+pub const PPC_FEATURE_HAS_VSX: c_ulong = 0x00000080;
+pub const PPC_FEATURE_PSERIES_PERFMON_COMPAT: c_ulong = 0x00000040;
+pub const PPC_FEATURE_TRUE_LE: c_ulong = 0x00000002;
+pub const PPC_FEATURE_PPC_LE: c_ulong = 0x00000001;
+
+pub const PPC_FEATURE2_ARCH_2_07: c_ulong = 0x80000000;
+pub const PPC_FEATURE2_HTM: c_ulong = 0x40000000;
+pub const PPC_FEATURE2_DSCR: c_ulong = 0x20000000;
+pub const PPC_FEATURE2_EBB: c_ulong = 0x10000000;
+pub const PPC_FEATURE2_ISEL: c_ulong = 0x08000000;
+pub const PPC_FEATURE2_TAR: c_ulong = 0x04000000;
+pub const PPC_FEATURE2_VEC_CRYPTO: c_ulong = 0x02000000;
+pub const PPC_FEATURE2_HTM_NOSC: c_ulong = 0x01000000;
+pub const PPC_FEATURE2_ARCH_3_00: c_ulong = 0x00800000;
+pub const PPC_FEATURE2_HAS_IEEE128: c_ulong = 0x00400000;
+pub const PPC_FEATURE2_DARN: c_ulong = 0x00200000;
+pub const PPC_FEATURE2_SCV: c_ulong = 0x00100000;
+pub const PPC_FEATURE2_HTM_NO_SUSPEND: c_ulong = 0x00080000;
+pub const PPC_FEATURE2_ARCH_3_1: c_ulong = 0x00040000;
+pub const PPC_FEATURE2_MMA: c_ulong = 0x00020000;
@@ -0,0 +1,14 @@
+//! Header: `arch/riscv/include/uapi/asm/hwcap.h`
+//!
+//! Bits of the `AT_HWCAP` auxiliary vector entry, one per single-letter ISA extension.
+
+use crate::prelude::*;
+
+pub const COMPAT_HWCAP_ISA_I: c_ulong = 1 << (b'I' - b'A');
+pub const COMPAT_HWCAP_ISA_M: c_ulong = 1 << (b'M' - b'A');
+#[allow(clippy::eq_op)]
+pub const COMPAT_HWCAP_ISA_A: c_ulong = 1 << (b'A' - b'A');
+pub const COMPAT_HWCAP_ISA_F: c_ulong = 1 << (b'F' - b'A');
+pub const COMPAT_HWCAP_ISA_D: c_ulong = 1 << (b'D' - b'A');
+pub const COMPAT_HWCAP_ISA_C: c_ulong = 1 << (b'C' - b'A');
+pub const COMPAT_HWCAP_ISA_V: c_ulong = 1 << (b'V' - b'A');
@@ -0,0 +1,111 @@
+//! Header: `arch/riscv/include/uapi/asm/hwprobe.h`
+//!
+//! Definitions for the `riscv_hwprobe` system call. Each [`riscv_hwprobe`] pair passed to the
+//! kernel names a `RISCV_HWPROBE_KEY_*` and receives the corresponding value; keys unknown to the
+//! running kernel come back with `key` set to -1.
+
+use crate::prelude::*;
+use crate::{
+    __s64,
+    __u64,
+};
+
+s! {
+    pub struct riscv_hwprobe {
+        pub key: __s64,
+        pub value: __u64,
+    }
+}
+
+pub const RISCV_HWPROBE_KEY_MVENDORID: __s64 = 0;
+pub const RISCV_HWPROBE_KEY_MARCHID: __s64 = 1;
+pub const RISCV_HWPROBE_KEY_MIMPID: __s64 = 2;
+pub const RISCV_HWPROBE_KEY_BASE_BEHAVIOR: __s64 = 3;
+pub const RISCV_HWPROBE_BASE_BEHAVIOR_IMA: __u64 = 1 << 0;
+pub const RISCV_HWPROBE_KEY_IMA_EXT_0: __s64 = 4;
+pub const RISCV_HWPROBE_IMA_FD: __u64 = 1 << 0;
+pub const RISCV_HWPROBE_IMA_C: __u64 = 1 << 1;
+pub const RISCV_HWPROBE_IMA_V: __u64 = 1 << 2;
+pub const RISCV_HWPROBE_EXT_ZBA: __u64 = 1 << 3;
+pub const RISCV_HWPROBE_EXT_ZBB: __u64 = 1 << 4;
+pub const RISCV_HWPROBE_EXT_ZBS: __u64 = 1 << 5;
+pub const RISCV_HWPROBE_EXT_ZICBOZ: __u64 = 1 << 6;
+pub const RISCV_HWPROBE_EXT_ZBC: __u64 = 1 << 7;
+pub const RISCV_HWPROBE_EXT_ZBKB: __u64 = 1 << 8;
+pub const RISCV_HWPROBE_EXT_ZBKC: __u64 = 1 << 9;
+pub const RISCV_HWPROBE_EXT_ZBKX: __u64 = 1 << 10;
+pub const RISCV_HWPROBE_EXT_ZKND: __u64 = 1 << 11;
+pub const RISCV_HWPROBE_EXT_ZKNE: __u64 = 1 << 12;
+pub const RISCV_HWPROBE_EXT_ZKNH: __u64 = 1 << 13;
+pub const RISCV_HWPROBE_EXT_ZKSED: __u64 = 1 << 14;
+pub const RISCV_HWPROBE_EXT_ZKSH: __u64 = 1 << 15;
+pub const RISCV_HWPROBE_EXT_ZKT: __u64 = 1 << 16;
+pub const RISCV_HWPROBE_EXT_ZVBB: __u64 = 1 << 17;
+pub const RISCV_HWPROBE_EXT_ZVBC: __u64 = 1 << 18;
+pub const RISCV_HWPROBE_EXT_ZVKB: __u64 = 1 << 19;
+pub const RISCV_HWPROBE_EXT_ZVKG: __u64 = 1 << 20;
+pub const RISCV_HWPROBE_EXT_ZVKNED: __u64 = 1 << 21;
+pub const RISCV_HWPROBE_EXT_ZVKNHA: __u64 = 1 << 22;
+pub const RISCV_HWPROBE_EXT_ZVKNHB: __u64 = 1 << 23;
+pub const RISCV_HWPROBE_EXT_ZVKSED: __u64 = 1 << 24;
+pub const RISCV_HWPROBE_EXT_ZVKSH: __u64 = 1 << 25;
+pub const RISCV_HWPROBE_EXT_ZVKT: __u64 = 1 << 26;
+pub const RISCV_HWPROBE_EXT_ZFH: __u64 = 1 << 27;
+pub const RISCV_HWPROBE_EXT_ZFHMIN: __u64 = 1 << 28;
+pub const RISCV_HWPROBE_EXT_ZIHINTNTL: __u64 = 1 << 29;
+pub const RISCV_HWPROBE_EXT_ZVFH: __u64 = 1 << 30;
+pub const RISCV_HWPROBE_EXT_ZVFHMIN: __u64 = 1 << 31;
+pub const RISCV_HWPROBE_EXT_ZFA: __u64 = 1 << 32;
+pub const RISCV_HWPROBE_EXT_ZTSO: __u64 = 1 << 33;
+pub const RISCV_HWPROBE_EXT_ZACAS: __u64 = 1 << 34;
+pub const RISCV_HWPROBE_EXT_ZICOND: __u64 = 1 << 35;
+pub const RISCV_HWPROBE_EXT_ZIHINTPAUSE: __u64 = 1 << 36;
+pub const RISCV_HWPROBE_EXT_ZVE32X: __u64 = 1 << 37;
+pub const RISCV_HWPROBE_EXT_ZVE32F: __u64 = 1 << 38;
+pub const RISCV_HWPROBE_EXT_ZVE64X: __u64 = 1 << 39;
+pub const RISCV_HWPROBE_EXT_ZVE64F: __u64 = 1 << 40;
+pub const RISCV_HWPROBE_EXT_ZVE64D: __u64 = 1 << 41;
+pub const RISCV_HWPROBE_EXT_ZIMOP: __u64 = 1 << 42;
+pub const RISCV_HWPROBE_EXT_ZCA: __u64 = 1 << 43;
+pub const RISCV_HWPROBE_EXT_ZCB: __u64 = 1 << 44;
+pub const RISCV_HWPROBE_EXT_ZCD: __u64 = 1 << 45;
+pub const RISCV_HWPROBE_EXT_ZCF: __u64 = 1 << 46;
+pub const RISCV_HWPROBE_EXT_ZCMOP: __u64 = 1 << 47;
+pub const RISCV_HWPROBE_EXT_ZAWRS: __u64 = 1 << 48;
+pub const RISCV_HWPROBE_EXT_SUPM: __u64 = 1 << 49;
+pub const RISCV_HWPROBE_EXT_ZICNTR: __u64 = 1 << 50;
+pub const RISCV_HWPROBE_EXT_ZIHPM: __u64 = 1 << 51;
+pub const RISCV_HWPROBE_EXT_ZFBFMIN: __u64 = 1 << 52;
+pub const RISCV_HWPROBE_EXT_ZVFBFMIN: __u64 = 1 << 53;
+pub const RISCV_HWPROBE_EXT_ZVFBFWMA: __u64 = 1 << 54;
+pub const RISCV_HWPROBE_EXT_ZICBOM: __u64 = 1 << 55;
+pub const RISCV_HWPROBE_EXT_ZAAMO: __u64 = 1 << 56;
+pub const RISCV_HWPROBE_EXT_ZALRSC: __u64 = 1 << 57;
+pub const RISCV_HWPROBE_EXT_ZABHA: __u64 = 1 << 58;
+/// Deprecated by the kernel in favor of [`RISCV_HWPROBE_KEY_MISALIGNED_SCALAR_PERF`].
+pub const RISCV_HWPROBE_KEY_CPUPERF_0: __s64 = 5;
+pub const RISCV_HWPROBE_MISALIGNED_UNKNOWN: __u64 = 0;
+pub const RISCV_HWPROBE_MISALIGNED_EMULATED: __u64 = 1;
+pub const RISCV_HWPROBE_MISALIGNED_SLOW: __u64 = 2;
+pub const RISCV_HWPROBE_MISALIGNED_FAST: __u64 = 3;
+pub const RISCV_HWPROBE_MISALIGNED_UNSUPPORTED: __u64 = 4;
+pub const RISCV_HWPROBE_MISALIGNED_MASK: __u64 = 7;
+pub const RISCV_HWPROBE_KEY_ZICBOZ_BLOCK_SIZE: __s64 = 6;
+pub const RISCV_HWPROBE_KEY_HIGHEST_VIRT_ADDRESS: __s64 = 7;
+pub const RISCV_HWPROBE_KEY_TIME_CSR_FREQ: __s64 = 8;
+pub const RISCV_HWPROBE_KEY_MISALIGNED_SCALAR_PERF: __s64 = 9;
+pub const RISCV_HWPROBE_MISALIGNED_SCALAR_UNKNOWN: __u64 = 0;
+pub const RISCV_HWPROBE_MISALIGNED_SCALAR_EMULATED: __u64 = 1;
+pub const RISCV_HWPROBE_MISALIGNED_SCALAR_SLOW: __u64 = 2;
+pub const RISCV_HWPROBE_MISALIGNED_SCALAR_FAST: __u64 = 3;
+pub const RISCV_HWPROBE_MISALIGNED_SCALAR_UNSUPPORTED: __u64 = 4;
+pub const RISCV_HWPROBE_KEY_MISALIGNED_VECTOR_PERF: __s64 = 10;
+pub const RISCV_HWPROBE_MISALIGNED_VECTOR_UNKNOWN: __u64 = 0;
+pub const RISCV_HWPROBE_MISALIGNED_VECTOR_SLOW: __u64 = 2;
+pub const RISCV_HWPROBE_MISALIGNED_VECTOR_FAST: __u64 = 3;
+pub const RISCV_HWPROBE_MISALIGNED_VECTOR_UNSUPPORTED: __u64 = 4;
+pub const RISCV_HWPROBE_KEY_ZICBOM_BLOCK_SIZE: __s64 = 12;
+
+/// Interpret the CPU set as an output: the kernel clears every CPU that does not match all of
+/// the given key/value pairs.
+pub const RISCV_HWPROBE_WHICH_CPUS: c_uint = 1 << 0;
@@ -0,0 +1,12 @@
+//! Header: `arch/sparc/include/uapi/asm/auxvec.h`
+
+use crate::prelude::*;
+
+// Application Data Integrity (ADI) properties.
+pub const AT_ADI_BLKSZ: c_ulong = 48;
+pub const AT_ADI_NBITS: c_ulong = 49;
+pub const AT_ADI_UEONADI: c_ulong = 50;
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const AT_VECTOR_SIZE_ARCH: c_ulong = 4;
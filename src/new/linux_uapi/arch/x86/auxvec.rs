@@ -0,0 +1,10 @@
+//! Header: `arch/x86/include/uapi/asm/auxvec.h`
+
+use crate::prelude::*;
+
+#[cfg(target_arch = "x86")]
+pub const AT_SYSINFO: c_ulong = 32;
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const AT_VECTOR_SIZE_ARCH: c_ulong = 3;
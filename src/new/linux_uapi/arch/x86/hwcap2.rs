@@ -0,0 +1,10 @@
+//! Header: `arch/x86/include/uapi/asm/hwcap2.h`
+//!
+//! Bits of the `AT_HWCAP2` auxiliary vector entry.
+
+use crate::prelude::*;
+
+/// `MONITOR`/`MWAIT` are enabled in ring 3.
+pub const HWCAP2_RING3MWAIT: c_ulong = 1 << 0;
+/// The kernel allows `FSGSBASE` instructions in ring 3.
+pub const HWCAP2_FSGSBASE: c_ulong = 1 << 1;
@@ -0,0 +1,40 @@
+//! Header: `uapi/linux/auxvec.h`
+//!
+//! Architecture-specific entry types are defined with the rest of the architecture's headers.
+
+use crate::prelude::*;
+
+pub const AT_NULL: c_ulong = 0;
+pub const AT_IGNORE: c_ulong = 1;
+pub const AT_EXECFD: c_ulong = 2;
+pub const AT_PHDR: c_ulong = 3;
+pub const AT_PHENT: c_ulong = 4;
+pub const AT_PHNUM: c_ulong = 5;
+pub const AT_PAGESZ: c_ulong = 6;
+pub const AT_BASE: c_ulong = 7;
+pub const AT_FLAGS: c_ulong = 8;
+pub const AT_ENTRY: c_ulong = 9;
+pub const AT_NOTELF: c_ulong = 10;
+pub const AT_UID: c_ulong = 11;
+pub const AT_EUID: c_ulong = 12;
+pub const AT_GID: c_ulong = 13;
+pub const AT_EGID: c_ulong = 14;
+pub const AT_PLATFORM: c_ulong = 15;
+pub const AT_HWCAP: c_ulong = 16;
+pub const AT_CLKTCK: c_ulong = 17;
+
+pub const AT_SECURE: c_ulong = 23;
+pub const AT_BASE_PLATFORM: c_ulong = 24;
+pub const AT_RANDOM: c_ulong = 25;
+pub const AT_HWCAP2: c_ulong = 26;
+pub const AT_RSEQ_FEATURE_SIZE: c_ulong = 27;
+pub const AT_RSEQ_ALIGN: c_ulong = 28;
+pub const AT_HWCAP3: c_ulong = 29;
+pub const AT_HWCAP4: c_ulong = 30;
+pub const AT_EXECFN: c_ulong = 31;
+
+// Defined in `asm/auxvec.h` but has the same value wherever it is defined.
+pub const AT_SYSINFO_EHDR: c_ulong = 33;
+
+#[cfg(not(target_env = "uclibc"))]
+pub const AT_MINSIGSTKSZ: c_ulong = 51;
@@ -0,0 +1,55 @@
+//! Header: `uapi/linux/bsg.h`
+
+use crate::prelude::*;
+use crate::{
+    __s32,
+    __u32,
+    __u64,
+};
+
+pub const BSG_PROTOCOL_SCSI: c_int = 0;
+
+pub const BSG_SUB_PROTOCOL_SCSI_CMD: c_int = 0;
+pub const BSG_SUB_PROTOCOL_SCSI_TMF: c_int = 1;
+pub const BSG_SUB_PROTOCOL_SCSI_TRANSPORT: c_int = 2;
+
+pub const BSG_FLAG_Q_AT_TAIL: c_int = 0x10;
+pub const BSG_FLAG_Q_AT_HEAD: c_int = 0x20;
+
+s! {
+    pub struct sg_io_v4 {
+        pub guard: __s32,
+        pub protocol: __u32,
+        pub subprotocol: __u32,
+        pub request_len: __u32,
+        pub request: __u64,
+        pub request_tag: __u64,
+        pub request_attr: __u32,
+        pub request_priority: __u32,
+        pub request_extra: __u32,
+        pub max_response_len: __u32,
+        pub response: __u64,
+        pub dout_iovec_count: __u32,
+        pub dout_xfer_len: __u32,
+        pub din_iovec_count: __u32,
+        pub din_xfer_len: __u32,
+        pub dout_xferp: __u64,
+        pub din_xferp: __u64,
+        pub timeout: __u32,
+        pub flags: __u32,
+        pub usr_ptr: __u64,
+        pub spare_in: __u32,
+        pub driver_status: __u32,
+        pub transport_status: __u32,
+        pub device_status: __u32,
+        pub retry_delay: __u32,
+        pub info: __u32,
+        pub duration: __u32,
+        pub response_len: __u32,
+        pub din_resid: __s32,
+        pub dout_resid: __s32,
+        pub generated_tag: __u64,
+        pub spare_out: __u32,
+        padding: Padding<__u32>,
+    }
+}
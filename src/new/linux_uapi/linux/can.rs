@@ -2,6 +2,7 @@
 
 pub(crate) mod bcm;
 pub(crate) mod error;
+pub(crate) mod isotp;
 pub(crate) mod j1939;
 pub(crate) mod netlink;
 pub(crate) mod raw;
@@ -26,6 +27,7 @@ pub const CANXL_PRIO_BITS: c_int = CAN_SFF_ID_BITS;
 pub type can_err_mask_t = u32;
 
 pub const CAN_MAX_DLC: c_int = 8;
+pub const CAN_MAX_RAW_DLC: c_int = 15;
 pub const CAN_MAX_DLEN: usize = 8;
 
 pub const CANFD_MAX_DLC: c_int = 15;
@@ -70,6 +72,10 @@ s_with_default! {
 pub const CANXL_XLF: c_int = 0x80;
 pub const CANXL_SEC: c_int = 0x01;
 
+pub const CANXL_VCID_OFFSET: c_int = 16;
+pub const CANXL_VCID_VAL_MASK: canid_t = 0xFF;
+pub const CANXL_VCID_MASK: canid_t = CANXL_VCID_VAL_MASK << CANXL_VCID_OFFSET;
+
 s_with_default! {
     pub struct canxl_frame {
         pub prio: canid_t,
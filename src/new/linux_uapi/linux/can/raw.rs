@@ -2,6 +2,15 @@
 
 pub use crate::linux::can::*;
 
+s! {
+    pub struct can_raw_vcid_options {
+        pub flags: u8,
+        pub tx_vcid: u8,
+        pub rx_vcid: u8,
+        pub rx_vcid_mask: u8,
+    }
+}
+
 pub const SOL_CAN_RAW: c_int = SOL_CAN_BASE + CAN_RAW;
 
 /// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
@@ -16,3 +25,10 @@ pub const CAN_RAW_RECV_OWN_MSGS: c_int = 4;
 pub const CAN_RAW_FD_FRAMES: c_int = 5;
 pub const CAN_RAW_JOIN_FILTERS: c_int = 6;
 pub const CAN_RAW_XL_FRAMES: c_int = 7;
+pub const CAN_RAW_XL_VCID_OPTS: c_int = 8;
+
+pub const SCM_CAN_RAW_ERRQUEUE: c_int = 1;
+
+pub const CAN_RAW_XL_VCID_TX_SET: u8 = 0x01;
+pub const CAN_RAW_XL_VCID_TX_PASS: u8 = 0x02;
+pub const CAN_RAW_XL_VCID_RX_FILTER: u8 = 0x04;
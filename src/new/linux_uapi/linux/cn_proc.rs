@@ -0,0 +1,137 @@
+//! Header: `uapi/linux/cn_proc.h`
+
+use crate::prelude::*;
+use crate::{
+    __u32,
+    __u64,
+};
+
+c_enum! {
+    pub enum proc_cn_mcast_op {
+        pub PROC_CN_MCAST_LISTEN = 1,
+        pub PROC_CN_MCAST_IGNORE = 2,
+    }
+
+    pub enum proc_cn_event {
+        pub PROC_EVENT_NONE = 0x00000000,
+        pub PROC_EVENT_FORK = 0x00000001,
+        pub PROC_EVENT_EXEC = 0x00000002,
+        pub PROC_EVENT_UID = 0x00000004,
+        pub PROC_EVENT_GID = 0x00000040,
+        pub PROC_EVENT_SID = 0x00000080,
+        pub PROC_EVENT_PTRACE = 0x00000100,
+        pub PROC_EVENT_COMM = 0x00000200,
+        pub PROC_EVENT_NONZERO_EXIT = 0x20000000,
+        pub PROC_EVENT_COREDUMP = 0x40000000,
+        pub PROC_EVENT_EXIT = 0x80000000,
+    }
+}
+
+s! {
+    pub struct proc_input {
+        pub mcast_op: proc_cn_mcast_op,
+        pub event_type: proc_cn_event,
+    }
+
+    pub struct __c_anonymous_proc_event_ack {
+        pub err: __u32,
+    }
+
+    pub struct fork_proc_event {
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+        pub child_pid: crate::pid_t,
+        pub child_tgid: crate::pid_t,
+    }
+
+    pub struct exec_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+    }
+
+    pub struct sid_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+    }
+
+    pub struct ptrace_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub tracer_pid: crate::pid_t,
+        pub tracer_tgid: crate::pid_t,
+    }
+
+    pub struct comm_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub comm: [c_char; 16],
+    }
+
+    pub struct coredump_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+    }
+
+    pub struct exit_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub exit_code: __u32,
+        pub exit_signal: __u32,
+        pub parent_pid: crate::pid_t,
+        pub parent_tgid: crate::pid_t,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_id_proc_event_r {
+        pub ruid: __u32,
+        pub rgid: __u32,
+    }
+
+    pub union __c_anonymous_id_proc_event_e {
+        pub euid: __u32,
+        pub egid: __u32,
+    }
+
+    pub struct id_proc_event {
+        pub process_pid: crate::pid_t,
+        pub process_tgid: crate::pid_t,
+        pub r: __c_anonymous_id_proc_event_r,
+        pub e: __c_anonymous_id_proc_event_e,
+    }
+
+    pub union __c_anonymous_proc_event_event_data {
+        pub ack: __c_anonymous_proc_event_ack,
+        pub fork: fork_proc_event,
+        pub exec: exec_proc_event,
+        pub id: id_proc_event,
+        pub sid: sid_proc_event,
+        pub ptrace: ptrace_proc_event,
+        pub comm: comm_proc_event,
+        pub coredump: coredump_proc_event,
+        pub exit: exit_proc_event,
+    }
+
+    #[repr(align(8))]
+    pub struct proc_event {
+        pub what: proc_cn_event,
+        pub cpu: __u32,
+        pub timestamp_ns: __u64,
+        pub event_data: __c_anonymous_proc_event_event_data,
+    }
+}
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const PROC_EVENT_ALL: proc_cn_event = PROC_EVENT_FORK
+    | PROC_EVENT_EXEC
+    | PROC_EVENT_UID
+    | PROC_EVENT_GID
+    | PROC_EVENT_SID
+    | PROC_EVENT_PTRACE
+    | PROC_EVENT_COMM
+    | PROC_EVENT_NONZERO_EXIT
+    | PROC_EVENT_COREDUMP
+    | PROC_EVENT_EXIT;
@@ -0,0 +1,50 @@
+//! Header: `uapi/linux/connector.h`
+
+use crate::prelude::*;
+use crate::{
+    __u16,
+    __u32,
+    __u8,
+};
+
+s! {
+    pub struct cb_id {
+        pub idx: __u32,
+        pub val: __u32,
+    }
+
+    pub struct cn_msg {
+        pub id: cb_id,
+        pub seq: __u32,
+        pub ack: __u32,
+        pub len: __u16,
+        pub flags: __u16,
+        pub data: [__u8; 0],
+    }
+}
+
+pub const CN_IDX_PROC: c_uint = 0x1;
+pub const CN_VAL_PROC: c_uint = 0x1;
+pub const CN_IDX_CIFS: c_uint = 0x2;
+pub const CN_VAL_CIFS: c_uint = 0x1;
+pub const CN_W1_IDX: c_uint = 0x3;
+pub const CN_W1_VAL: c_uint = 0x1;
+pub const CN_IDX_V86D: c_uint = 0x4;
+pub const CN_VAL_V86D_UVESAFB: c_uint = 0x1;
+pub const CN_IDX_BB: c_uint = 0x5;
+pub const CN_DST_IDX: c_uint = 0x6;
+pub const CN_DST_VAL: c_uint = 0x1;
+pub const CN_IDX_DM: c_uint = 0x7;
+pub const CN_VAL_DM_USERSPACE_LOG: c_uint = 0x1;
+pub const CN_IDX_DRBD: c_uint = 0x8;
+pub const CN_VAL_DRBD: c_uint = 0x1;
+pub const CN_KVP_IDX: c_uint = 0x9;
+pub const CN_KVP_VAL: c_uint = 0x1;
+pub const CN_VSS_IDX: c_uint = 0xA;
+pub const CN_VSS_VAL: c_uint = 0x1;
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const CN_NETLINK_USERS: c_uint = 11;
+
+pub const CONNECTOR_MAX_MSG_SIZE: c_uint = 16384;
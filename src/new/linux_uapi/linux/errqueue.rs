@@ -0,0 +1,68 @@
+//! Header: `uapi/linux/errqueue.h`
+
+use crate::prelude::*;
+use crate::{
+    __kernel_timespec,
+    __u16,
+    __u8,
+};
+
+s! {
+    pub struct sock_ee_data_rfc4884 {
+        pub len: __u16,
+        pub flags: __u8,
+        reserved: Padding<__u8>,
+    }
+
+    pub struct sock_extended_err {
+        pub ee_errno: u32,
+        pub ee_origin: u8,
+        pub ee_type: u8,
+        pub ee_code: u8,
+        pub ee_pad: u8,
+        pub ee_info: u32,
+        pub ee_data: u32,
+    }
+
+    pub struct scm_timestamping {
+        pub ts: [crate::timespec; 3],
+    }
+
+    pub struct scm_timestamping64 {
+        pub ts: [__kernel_timespec; 3],
+    }
+}
+
+pub const SO_EE_ORIGIN_NONE: u8 = 0;
+pub const SO_EE_ORIGIN_LOCAL: u8 = 1;
+pub const SO_EE_ORIGIN_ICMP: u8 = 2;
+pub const SO_EE_ORIGIN_ICMP6: u8 = 3;
+pub const SO_EE_ORIGIN_TXSTATUS: u8 = 4;
+pub const SO_EE_ORIGIN_ZEROCOPY: u8 = 5;
+pub const SO_EE_ORIGIN_TXTIME: u8 = 6;
+pub const SO_EE_ORIGIN_TIMESTAMPING: u8 = SO_EE_ORIGIN_TXSTATUS;
+
+pub const SO_EE_CODE_ZEROCOPY_COPIED: u8 = 1;
+
+pub const SO_EE_CODE_TXTIME_INVALID_PARAM: u8 = 1;
+pub const SO_EE_CODE_TXTIME_MISSED: u8 = 2;
+
+pub const SO_EE_RFC4884_FLAG_INVALID: u8 = 1;
+
+// The type of `scm_timestamping`, passed in `sock_extended_err.ee_info` when `ee_origin` is
+// `SO_EE_ORIGIN_TIMESTAMPING`.
+c_enum! {
+    #[repr(u32)]
+    pub enum #anon {
+        pub SCM_TSTAMP_SND,
+        pub SCM_TSTAMP_SCHED,
+        pub SCM_TSTAMP_ACK,
+        pub SCM_TSTAMP_COMPLETION,
+    }
+}
+
+f! {
+    pub unsafe fn SO_EE_OFFENDER(ee: *const sock_extended_err) -> *mut crate::sockaddr {
+        ee.offset(1) as *mut crate::sockaddr
+    }
+}
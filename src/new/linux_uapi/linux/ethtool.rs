@@ -0,0 +1,302 @@
+//! Header: `uapi/linux/ethtool.h`
+
+use crate::prelude::*;
+use crate::{
+    __s8,
+    __u16,
+    __u32,
+    __u64,
+    __u8,
+};
+
+s! {
+    pub struct ethtool_cmd {
+        pub cmd: __u32,
+        pub supported: __u32,
+        pub advertising: __u32,
+        pub speed: __u16,
+        pub duplex: __u8,
+        pub port: __u8,
+        pub phy_address: __u8,
+        pub transceiver: __u8,
+        pub autoneg: __u8,
+        pub mdio_support: __u8,
+        pub maxtxpkt: __u32,
+        pub maxrxpkt: __u32,
+        pub speed_hi: __u16,
+        pub eth_tp_mdix: __u8,
+        pub eth_tp_mdix_ctrl: __u8,
+        pub lp_advertising: __u32,
+        reserved: Padding<[__u32; 2]>,
+    }
+
+    pub struct ethtool_drvinfo {
+        pub cmd: __u32,
+        pub driver: [c_char; 32],
+        pub version: [c_char; 32],
+        pub fw_version: [c_char; ETHTOOL_FWVERS_LEN],
+        pub bus_info: [c_char; ETHTOOL_BUSINFO_LEN],
+        pub erom_version: [c_char; ETHTOOL_EROMVERS_LEN],
+        reserved2: Padding<[c_char; 12]>,
+        pub n_priv_flags: __u32,
+        pub n_stats: __u32,
+        pub testinfo_len: __u32,
+        pub eedump_len: __u32,
+        pub regdump_len: __u32,
+    }
+
+    pub struct ethtool_value {
+        pub cmd: __u32,
+        pub data: __u32,
+    }
+
+    pub struct ethtool_coalesce {
+        pub cmd: __u32,
+        pub rx_coalesce_usecs: __u32,
+        pub rx_max_coalesced_frames: __u32,
+        pub rx_coalesce_usecs_irq: __u32,
+        pub rx_max_coalesced_frames_irq: __u32,
+        pub tx_coalesce_usecs: __u32,
+        pub tx_max_coalesced_frames: __u32,
+        pub tx_coalesce_usecs_irq: __u32,
+        pub tx_max_coalesced_frames_irq: __u32,
+        pub stats_block_coalesce_usecs: __u32,
+        pub use_adaptive_rx_coalesce: __u32,
+        pub use_adaptive_tx_coalesce: __u32,
+        pub pkt_rate_low: __u32,
+        pub rx_coalesce_usecs_low: __u32,
+        pub rx_max_coalesced_frames_low: __u32,
+        pub tx_coalesce_usecs_low: __u32,
+        pub tx_max_coalesced_frames_low: __u32,
+        pub pkt_rate_high: __u32,
+        pub rx_coalesce_usecs_high: __u32,
+        pub rx_max_coalesced_frames_high: __u32,
+        pub tx_coalesce_usecs_high: __u32,
+        pub tx_max_coalesced_frames_high: __u32,
+        pub rate_sample_interval: __u32,
+    }
+
+    pub struct ethtool_ringparam {
+        pub cmd: __u32,
+        pub rx_max_pending: __u32,
+        pub rx_mini_max_pending: __u32,
+        pub rx_jumbo_max_pending: __u32,
+        pub tx_max_pending: __u32,
+        pub rx_pending: __u32,
+        pub rx_mini_pending: __u32,
+        pub rx_jumbo_pending: __u32,
+        pub tx_pending: __u32,
+    }
+
+    pub struct ethtool_channels {
+        pub cmd: __u32,
+        pub max_rx: __u32,
+        pub max_tx: __u32,
+        pub max_other: __u32,
+        pub max_combined: __u32,
+        pub rx_count: __u32,
+        pub tx_count: __u32,
+        pub other_count: __u32,
+        pub combined_count: __u32,
+    }
+
+    pub struct ethtool_gstrings {
+        pub cmd: __u32,
+        pub string_set: __u32,
+        pub len: __u32,
+        pub data: [__u8; 0],
+    }
+
+    pub struct ethtool_sset_info {
+        pub cmd: __u32,
+        reserved: Padding<__u32>,
+        pub sset_mask: __u64,
+        pub data: [__u32; 0],
+    }
+
+    pub struct ethtool_stats {
+        pub cmd: __u32,
+        pub n_stats: __u32,
+        pub data: [__u64; 0],
+    }
+
+    pub struct ethtool_link_settings {
+        pub cmd: __u32,
+        pub speed: __u32,
+        pub duplex: __u8,
+        pub port: __u8,
+        pub phy_address: __u8,
+        pub autoneg: __u8,
+        pub mdio_support: __u8,
+        pub eth_tp_mdix: __u8,
+        pub eth_tp_mdix_ctrl: __u8,
+        pub link_mode_masks_nwords: __s8,
+        pub transceiver: __u8,
+        pub master_slave_cfg: __u8,
+        pub master_slave_state: __u8,
+        pub rate_matching: __u8,
+        reserved: Padding<[__u32; 7]>,
+        pub link_mode_masks: [__u32; 0],
+    }
+}
+
+pub const ETH_MDIO_SUPPORTS_C22: c_int = 1;
+pub const ETH_MDIO_SUPPORTS_C45: c_int = 2;
+
+pub const ETHTOOL_FWVERS_LEN: usize = 32;
+pub const ETHTOOL_BUSINFO_LEN: usize = 32;
+pub const ETHTOOL_EROMVERS_LEN: usize = 32;
+
+pub const ETH_GSTRING_LEN: c_int = 32;
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub ETH_SS_TEST = 0,
+        pub ETH_SS_STATS,
+        pub ETH_SS_PRIV_FLAGS,
+        pub ETH_SS_NTUPLE_FILTERS,
+        pub ETH_SS_FEATURES,
+        pub ETH_SS_RSS_HASH_FUNCS,
+        pub ETH_SS_TUNABLES,
+        pub ETH_SS_PHY_STATS,
+        pub ETH_SS_PHY_TUNABLES,
+        pub ETH_SS_LINK_MODES,
+        pub ETH_SS_MSG_CLASSES,
+        pub ETH_SS_WOL_MODES,
+        pub ETH_SS_SOF_TIMESTAMPING,
+        pub ETH_SS_TS_TX_TYPES,
+        pub ETH_SS_TS_RX_FILTERS,
+        pub ETH_SS_UDP_TUNNEL_TYPES,
+        pub ETH_SS_STATS_STD,
+        pub ETH_SS_STATS_ETH_PHY,
+        pub ETH_SS_STATS_ETH_MAC,
+        pub ETH_SS_STATS_ETH_CTRL,
+        pub ETH_SS_STATS_RMON,
+    }
+}
+
+// CMDs currently supported
+pub const ETHTOOL_GSET: __u32 = 0x00000001;
+pub const ETHTOOL_SSET: __u32 = 0x00000002;
+pub const ETHTOOL_GDRVINFO: __u32 = 0x00000003;
+pub const ETHTOOL_GREGS: __u32 = 0x00000004;
+pub const ETHTOOL_GWOL: __u32 = 0x00000005;
+pub const ETHTOOL_SWOL: __u32 = 0x00000006;
+pub const ETHTOOL_GMSGLVL: __u32 = 0x00000007;
+pub const ETHTOOL_SMSGLVL: __u32 = 0x00000008;
+pub const ETHTOOL_NWAY_RST: __u32 = 0x00000009;
+pub const ETHTOOL_GLINK: __u32 = 0x0000000a;
+pub const ETHTOOL_GEEPROM: __u32 = 0x0000000b;
+pub const ETHTOOL_SEEPROM: __u32 = 0x0000000c;
+pub const ETHTOOL_GCOALESCE: __u32 = 0x0000000e;
+pub const ETHTOOL_SCOALESCE: __u32 = 0x0000000f;
+pub const ETHTOOL_GRINGPARAM: __u32 = 0x00000010;
+pub const ETHTOOL_SRINGPARAM: __u32 = 0x00000011;
+pub const ETHTOOL_GPAUSEPARAM: __u32 = 0x00000012;
+pub const ETHTOOL_SPAUSEPARAM: __u32 = 0x00000013;
+pub const ETHTOOL_GRXCSUM: __u32 = 0x00000014;
+pub const ETHTOOL_SRXCSUM: __u32 = 0x00000015;
+pub const ETHTOOL_GTXCSUM: __u32 = 0x00000016;
+pub const ETHTOOL_STXCSUM: __u32 = 0x00000017;
+pub const ETHTOOL_GSG: __u32 = 0x00000018;
+pub const ETHTOOL_SSG: __u32 = 0x00000019;
+pub const ETHTOOL_TEST: __u32 = 0x0000001a;
+pub const ETHTOOL_GSTRINGS: __u32 = 0x0000001b;
+pub const ETHTOOL_PHYS_ID: __u32 = 0x0000001c;
+pub const ETHTOOL_GSTATS: __u32 = 0x0000001d;
+pub const ETHTOOL_GTSO: __u32 = 0x0000001e;
+pub const ETHTOOL_STSO: __u32 = 0x0000001f;
+pub const ETHTOOL_GPERMADDR: __u32 = 0x00000020;
+pub const ETHTOOL_GUFO: __u32 = 0x00000021;
+pub const ETHTOOL_SUFO: __u32 = 0x00000022;
+pub const ETHTOOL_GGSO: __u32 = 0x00000023;
+pub const ETHTOOL_SGSO: __u32 = 0x00000024;
+pub const ETHTOOL_GFLAGS: __u32 = 0x00000025;
+pub const ETHTOOL_SFLAGS: __u32 = 0x00000026;
+pub const ETHTOOL_GPFLAGS: __u32 = 0x00000027;
+pub const ETHTOOL_SPFLAGS: __u32 = 0x00000028;
+pub const ETHTOOL_GRXFH: __u32 = 0x00000029;
+pub const ETHTOOL_SRXFH: __u32 = 0x0000002a;
+pub const ETHTOOL_GGRO: __u32 = 0x0000002b;
+pub const ETHTOOL_SGRO: __u32 = 0x0000002c;
+pub const ETHTOOL_GRXRINGS: __u32 = 0x0000002d;
+pub const ETHTOOL_GRXCLSRLCNT: __u32 = 0x0000002e;
+pub const ETHTOOL_GRXCLSRULE: __u32 = 0x0000002f;
+pub const ETHTOOL_GRXCLSRLALL: __u32 = 0x00000030;
+pub const ETHTOOL_SRXCLSRLDEL: __u32 = 0x00000031;
+pub const ETHTOOL_SRXCLSRLINS: __u32 = 0x00000032;
+pub const ETHTOOL_FLASHDEV: __u32 = 0x00000033;
+pub const ETHTOOL_RESET: __u32 = 0x00000034;
+pub const ETHTOOL_SRXNTUPLE: __u32 = 0x00000035;
+pub const ETHTOOL_GRXNTUPLE: __u32 = 0x00000036;
+pub const ETHTOOL_GSSET_INFO: __u32 = 0x00000037;
+pub const ETHTOOL_GRXFHINDIR: __u32 = 0x00000038;
+pub const ETHTOOL_SRXFHINDIR: __u32 = 0x00000039;
+pub const ETHTOOL_GFEATURES: __u32 = 0x0000003a;
+pub const ETHTOOL_SFEATURES: __u32 = 0x0000003b;
+pub const ETHTOOL_GCHANNELS: __u32 = 0x0000003c;
+pub const ETHTOOL_SCHANNELS: __u32 = 0x0000003d;
+pub const ETHTOOL_SET_DUMP: __u32 = 0x0000003e;
+pub const ETHTOOL_GET_DUMP_FLAG: __u32 = 0x0000003f;
+pub const ETHTOOL_GET_DUMP_DATA: __u32 = 0x00000040;
+pub const ETHTOOL_GET_TS_INFO: __u32 = 0x00000041;
+pub const ETHTOOL_GMODULEINFO: __u32 = 0x00000042;
+pub const ETHTOOL_GMODULEEEPROM: __u32 = 0x00000043;
+pub const ETHTOOL_GEEE: __u32 = 0x00000044;
+pub const ETHTOOL_SEEE: __u32 = 0x00000045;
+pub const ETHTOOL_GRSSH: __u32 = 0x00000046;
+pub const ETHTOOL_SRSSH: __u32 = 0x00000047;
+pub const ETHTOOL_GTUNABLE: __u32 = 0x00000048;
+pub const ETHTOOL_STUNABLE: __u32 = 0x00000049;
+pub const ETHTOOL_GPHYSTATS: __u32 = 0x0000004a;
+pub const ETHTOOL_PERQUEUE: __u32 = 0x0000004b;
+pub const ETHTOOL_GLINKSETTINGS: __u32 = 0x0000004c;
+pub const ETHTOOL_SLINKSETTINGS: __u32 = 0x0000004d;
+pub const ETHTOOL_PHY_GTUNABLE: __u32 = 0x0000004e;
+pub const ETHTOOL_PHY_STUNABLE: __u32 = 0x0000004f;
+pub const ETHTOOL_GFECPARAM: __u32 = 0x00000050;
+pub const ETHTOOL_SFECPARAM: __u32 = 0x00000051;
+
+// The forced speed, in units of 1Mb. All values 0 to INT_MAX are legal.
+pub const SPEED_10: c_int = 10;
+pub const SPEED_100: c_int = 100;
+pub const SPEED_1000: c_int = 1000;
+pub const SPEED_2500: c_int = 2500;
+pub const SPEED_5000: c_int = 5000;
+pub const SPEED_10000: c_int = 10000;
+pub const SPEED_14000: c_int = 14000;
+pub const SPEED_20000: c_int = 20000;
+pub const SPEED_25000: c_int = 25000;
+pub const SPEED_40000: c_int = 40000;
+pub const SPEED_50000: c_int = 50000;
+pub const SPEED_56000: c_int = 56000;
+pub const SPEED_100000: c_int = 100000;
+pub const SPEED_200000: c_int = 200000;
+pub const SPEED_400000: c_int = 400000;
+pub const SPEED_UNKNOWN: c_int = -1;
+
+// Duplex, half or full.
+pub const DUPLEX_HALF: __u8 = 0x00;
+pub const DUPLEX_FULL: __u8 = 0x01;
+pub const DUPLEX_UNKNOWN: __u8 = 0xff;
+
+// Which connector port.
+pub const PORT_TP: __u8 = 0x00;
+pub const PORT_AUI: __u8 = 0x01;
+pub const PORT_MII: __u8 = 0x02;
+pub const PORT_FIBRE: __u8 = 0x03;
+pub const PORT_BNC: __u8 = 0x04;
+pub const PORT_DA: __u8 = 0x05;
+pub const PORT_NONE: __u8 = 0xef;
+pub const PORT_OTHER: __u8 = 0xff;
+
+// Enable or disable autonegotiation.
+pub const AUTONEG_DISABLE: __u8 = 0x00;
+pub const AUTONEG_ENABLE: __u8 = 0x01;
+
+// MDI or MDI-X status/control
+pub const ETH_TP_MDI_INVALID: __u8 = 0x00;
+pub const ETH_TP_MDI: __u8 = 0x01;
+pub const ETH_TP_MDI_X: __u8 = 0x02;
+pub const ETH_TP_MDI_AUTO: __u8 = 0x03;
@@ -1,5 +1,6 @@
 //! Header: `uapi/linux/if_link.h`
 
+use crate::__u32;
 use crate::prelude::*;
 
 c_enum! {
@@ -81,3 +82,40 @@ c_enum! {
         pub IFLA_INFO_SLAVE_DATA,
     }
 }
+
+// XDP section
+
+pub const XDP_FLAGS_UPDATE_IF_NOEXIST: __u32 = 1 << 0;
+pub const XDP_FLAGS_SKB_MODE: __u32 = 1 << 1;
+pub const XDP_FLAGS_DRV_MODE: __u32 = 1 << 2;
+pub const XDP_FLAGS_HW_MODE: __u32 = 1 << 3;
+pub const XDP_FLAGS_REPLACE: __u32 = 1 << 4;
+pub const XDP_FLAGS_MODES: __u32 = XDP_FLAGS_SKB_MODE | XDP_FLAGS_DRV_MODE | XDP_FLAGS_HW_MODE;
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const XDP_FLAGS_MASK: __u32 = XDP_FLAGS_UPDATE_IF_NOEXIST | XDP_FLAGS_MODES | XDP_FLAGS_REPLACE;
+
+c_enum! {
+    // These are stored into IFLA_XDP_ATTACHED on dump.
+    #[repr(c_uchar)]
+    pub enum #anon {
+        pub XDP_ATTACHED_NONE = 0,
+        pub XDP_ATTACHED_DRV,
+        pub XDP_ATTACHED_SKB,
+        pub XDP_ATTACHED_HW,
+        pub XDP_ATTACHED_MULTI,
+    }
+
+    #[repr(c_ushort)]
+    pub enum #anon {
+        pub IFLA_XDP_UNSPEC,
+        pub IFLA_XDP_FD,
+        pub IFLA_XDP_ATTACHED,
+        pub IFLA_XDP_FLAGS,
+        pub IFLA_XDP_PROG_ID,
+        pub IFLA_XDP_DRV_PROG_ID,
+        pub IFLA_XDP_SKB_PROG_ID,
+        pub IFLA_XDP_HW_PROG_ID,
+        pub IFLA_XDP_EXPECTED_FD,
+    }
+}
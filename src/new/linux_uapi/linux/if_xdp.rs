@@ -0,0 +1,170 @@
+//! Header: `uapi/linux/if_xdp.h`
+
+use crate::prelude::*;
+use crate::{
+    __u16,
+    __u32,
+    __u64,
+};
+
+// Options for the sxdp_flags field
+pub const XDP_SHARED_UMEM: __u16 = 1 << 0;
+pub const XDP_COPY: __u16 = 1 << 1;
+pub const XDP_ZEROCOPY: __u16 = 1 << 2;
+pub const XDP_USE_NEED_WAKEUP: __u16 = 1 << 3;
+pub const XDP_USE_SG: __u16 = 1 << 4;
+
+// Flags for xsk_umem_config flags
+pub const XDP_UMEM_UNALIGNED_CHUNK_FLAG: __u32 = 1 << 0;
+pub const XDP_UMEM_TX_SW_CSUM: __u32 = 1 << 1;
+pub const XDP_UMEM_TX_METADATA_LEN: __u32 = 1 << 2;
+
+s! {
+    pub struct sockaddr_xdp {
+        pub sxdp_family: __u16,
+        pub sxdp_flags: __u16,
+        pub sxdp_ifindex: __u32,
+        pub sxdp_queue_id: __u32,
+        pub sxdp_shared_umem_fd: __u32,
+    }
+
+    pub struct xdp_ring_offset {
+        pub producer: __u64,
+        pub consumer: __u64,
+        pub desc: __u64,
+        pub flags: __u64,
+    }
+
+    pub struct xdp_mmap_offsets {
+        pub rx: xdp_ring_offset,
+        pub tx: xdp_ring_offset,
+        pub fr: xdp_ring_offset,
+        pub cr: xdp_ring_offset,
+    }
+
+    /// The `xdp_ring_offset` layout used before Linux 5.4.
+    pub struct xdp_ring_offset_v1 {
+        pub producer: __u64,
+        pub consumer: __u64,
+        pub desc: __u64,
+    }
+
+    /// The `xdp_mmap_offsets` layout used before Linux 5.4.
+    pub struct xdp_mmap_offsets_v1 {
+        pub rx: xdp_ring_offset_v1,
+        pub tx: xdp_ring_offset_v1,
+        pub fr: xdp_ring_offset_v1,
+        pub cr: xdp_ring_offset_v1,
+    }
+
+    pub struct xdp_umem_reg {
+        pub addr: __u64,
+        pub len: __u64,
+        pub chunk_size: __u32,
+        pub headroom: __u32,
+        pub flags: __u32,
+        pub tx_metadata_len: __u32,
+    }
+
+    /// The `xdp_umem_reg` layout used before Linux 5.4.
+    pub struct xdp_umem_reg_v1 {
+        pub addr: __u64,
+        pub len: __u64,
+        pub chunk_size: __u32,
+        pub headroom: __u32,
+    }
+
+    pub struct xdp_statistics {
+        pub rx_dropped: __u64,
+        pub rx_invalid_descs: __u64,
+        pub tx_invalid_descs: __u64,
+        pub rx_ring_full: __u64,
+        pub rx_fill_ring_empty_descs: __u64,
+        pub tx_ring_empty_descs: __u64,
+    }
+
+    /// The `xdp_statistics` layout used before Linux 5.9.
+    pub struct xdp_statistics_v1 {
+        pub rx_dropped: __u64,
+        pub rx_invalid_descs: __u64,
+        pub tx_invalid_descs: __u64,
+    }
+
+    pub struct xdp_options {
+        pub flags: __u32,
+    }
+
+    pub struct xsk_tx_metadata_completion {
+        pub tx_timestamp: __u64,
+    }
+
+    pub struct xsk_tx_metadata_request {
+        pub csum_start: __u16,
+        pub csum_offset: __u16,
+    }
+
+    pub struct xdp_desc {
+        pub addr: __u64,
+        pub len: __u32,
+        pub options: __u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub struct xsk_tx_metadata {
+        pub flags: __u64,
+        pub xsk_tx_metadata_union: __c_anonymous_xsk_tx_metadata_union,
+    }
+
+    pub union __c_anonymous_xsk_tx_metadata_union {
+        pub request: xsk_tx_metadata_request,
+        pub completion: xsk_tx_metadata_completion,
+    }
+}
+
+// XDP_RING flags
+pub const XDP_RING_NEED_WAKEUP: __u32 = 1 << 0;
+
+// XDP socket options
+pub const XDP_MMAP_OFFSETS: c_int = 1;
+pub const XDP_RX_RING: c_int = 2;
+pub const XDP_TX_RING: c_int = 3;
+pub const XDP_UMEM_REG: c_int = 4;
+pub const XDP_UMEM_FILL_RING: c_int = 5;
+pub const XDP_UMEM_COMPLETION_RING: c_int = 6;
+pub const XDP_STATISTICS: c_int = 7;
+pub const XDP_OPTIONS: c_int = 8;
+
+// Flags for the flags field of struct xdp_options
+pub const XDP_OPTIONS_ZEROCOPY: __u32 = 1 << 0;
+
+// Pgoff for mmaping the rings
+pub const XDP_PGOFF_RX_RING: crate::off_t = 0;
+pub const XDP_PGOFF_TX_RING: crate::off_t = 0x80000000u32 as crate::off_t;
+pub const XDP_UMEM_PGOFF_FILL_RING: c_ulonglong = 0x100000000;
+pub const XDP_UMEM_PGOFF_COMPLETION_RING: c_ulonglong = 0x180000000;
+
+// Masks for unaligned chunks mode
+pub const XSK_UNALIGNED_BUF_OFFSET_SHIFT: c_int = 48;
+pub const XSK_UNALIGNED_BUF_ADDR_MASK: c_ulonglong = (1 << XSK_UNALIGNED_BUF_OFFSET_SHIFT) - 1;
+
+// Request transmit timestamp. Upon completion, put it into tx_timestamp
+// field of struct xsk_tx_metadata.
+pub const XDP_TXMD_FLAGS_TIMESTAMP: __u32 = 1 << 0;
+
+// Request transmit checksum offload. Checksum start position and offset
+// are communicated via csum_start and csum_offset fields of struct
+// xsk_tx_metadata.
+pub const XDP_TXMD_FLAGS_CHECKSUM: __u32 = 1 << 1;
+
+// Request launch time hardware offload. The device will schedule the packet for
+// transmission at a pre-determined time called launch time. The value of
+// launch time is communicated via launch_time field of struct xsk_tx_metadata.
+// FIXME(1.0): `xsk_tx_metadata_request` does not have the `launch_time` field yet.
+pub const XDP_TXMD_FLAGS_LAUNCH_TIME: __u32 = 1 << 2;
+
+// Flag indicating packet constitutes of multiple buffers
+pub const XDP_PKT_CONTD: __u32 = 1 << 0;
+
+// TX packet carries valid metadata.
+pub const XDP_TX_METADATA: __u32 = 1 << 1;
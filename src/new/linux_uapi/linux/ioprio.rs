@@ -0,0 +1,93 @@
+//! Header: `uapi/linux/ioprio.h`
+
+use crate::prelude::*;
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub IOPRIO_CLASS_NONE = 0,
+        pub IOPRIO_CLASS_RT = 1,
+        pub IOPRIO_CLASS_BE = 2,
+        pub IOPRIO_CLASS_IDLE = 3,
+        pub IOPRIO_CLASS_INVALID = 7,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub IOPRIO_WHO_PROCESS = 1,
+        pub IOPRIO_WHO_PGRP,
+        pub IOPRIO_WHO_USER,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub IOPRIO_HINT_NONE = 0,
+        pub IOPRIO_HINT_DEV_DURATION_LIMIT_1 = 1,
+        pub IOPRIO_HINT_DEV_DURATION_LIMIT_2 = 2,
+        pub IOPRIO_HINT_DEV_DURATION_LIMIT_3 = 3,
+        pub IOPRIO_HINT_DEV_DURATION_LIMIT_4 = 4,
+        pub IOPRIO_HINT_DEV_DURATION_LIMIT_5 = 5,
+        pub IOPRIO_HINT_DEV_DURATION_LIMIT_6 = 6,
+        pub IOPRIO_HINT_DEV_DURATION_LIMIT_7 = 7,
+    }
+}
+
+pub const IOPRIO_CLASS_SHIFT: c_int = 13;
+pub const IOPRIO_NR_CLASSES: c_int = 8;
+pub const IOPRIO_CLASS_MASK: c_int = IOPRIO_NR_CLASSES - 1;
+pub const IOPRIO_PRIO_MASK: c_ulong = (1 << IOPRIO_CLASS_SHIFT) - 1;
+
+pub const IOPRIO_LEVEL_NR_BITS: c_int = 3;
+pub const IOPRIO_NR_LEVELS: c_int = 1 << IOPRIO_LEVEL_NR_BITS;
+pub const IOPRIO_LEVEL_MASK: c_int = IOPRIO_NR_LEVELS - 1;
+
+pub const IOPRIO_BE_NR: c_int = IOPRIO_NR_LEVELS;
+
+pub const IOPRIO_NORM: c_int = 4;
+pub const IOPRIO_BE_NORM: c_int = IOPRIO_NORM;
+
+pub const IOPRIO_HINT_SHIFT: c_int = IOPRIO_LEVEL_NR_BITS;
+pub const IOPRIO_HINT_NR_BITS: c_int = 10;
+pub const IOPRIO_NR_HINTS: c_int = 1 << IOPRIO_HINT_NR_BITS;
+pub const IOPRIO_HINT_MASK: c_int = IOPRIO_NR_HINTS - 1;
+
+f! {
+    pub const safe fn IOPRIO_PRIO_CLASS(ioprio: c_int) -> c_int {
+        (ioprio >> IOPRIO_CLASS_SHIFT) & IOPRIO_CLASS_MASK
+    }
+
+    pub const safe fn IOPRIO_PRIO_DATA(ioprio: c_int) -> c_int {
+        ioprio & IOPRIO_PRIO_MASK as c_int
+    }
+
+    pub const safe fn IOPRIO_PRIO_LEVEL(ioprio: c_int) -> c_int {
+        ioprio & IOPRIO_LEVEL_MASK
+    }
+
+    pub const safe fn IOPRIO_PRIO_HINT(ioprio: c_int) -> c_int {
+        (ioprio >> IOPRIO_HINT_SHIFT) & IOPRIO_HINT_MASK
+    }
+
+    /// Returns a priority with [`IOPRIO_CLASS_INVALID`] if any argument is out of range.
+    pub const safe fn IOPRIO_PRIO_VALUE_HINT(
+        prioclass: c_int,
+        priolevel: c_int,
+        priohint: c_int,
+    ) -> c_int {
+        if prioclass < 0
+            || prioclass >= IOPRIO_NR_CLASSES
+            || priolevel < 0
+            || priolevel >= IOPRIO_NR_LEVELS
+            || priohint < 0
+            || priohint >= IOPRIO_NR_HINTS
+        {
+            return IOPRIO_CLASS_INVALID << IOPRIO_CLASS_SHIFT;
+        }
+
+        (prioclass << IOPRIO_CLASS_SHIFT) | (priohint << IOPRIO_HINT_SHIFT) | priolevel
+    }
+
+    pub const safe fn IOPRIO_PRIO_VALUE(prioclass: c_int, priolevel: c_int) -> c_int {
+        IOPRIO_PRIO_VALUE_HINT(prioclass, priolevel, IOPRIO_HINT_NONE)
+    }
+}
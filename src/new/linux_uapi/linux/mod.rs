@@ -2,16 +2,45 @@
 //!
 //! <https://github.com/torvalds/linux/tree/master/include/uapi/linux>
 
+pub(crate) mod auxvec;
+pub(crate) mod bsg;
 pub(crate) mod can;
+pub(crate) mod cn_proc;
+pub(crate) mod connector;
+pub(crate) mod errqueue;
+pub(crate) mod ethtool;
+pub(crate) mod ethtool_netlink;
 pub(crate) mod futex;
 pub(crate) mod if_addr;
 pub(crate) mod if_link;
 pub(crate) mod if_packet;
+pub(crate) mod if_xdp;
+pub(crate) mod ioprio;
 pub(crate) mod keyctl;
 pub(crate) mod membarrier;
 pub(crate) mod mount;
+pub(crate) mod mptcp;
+pub(crate) mod net_tstamp;
 pub(crate) mod netlink;
+pub(crate) mod nvme_ioctl;
 pub(crate) mod pidfd;
+pub(crate) mod rseq;
+pub(crate) mod rtc;
 pub(crate) mod sctp;
+pub(crate) mod tcp;
+pub(crate) mod time_types;
 pub(crate) mod tls;
 pub(crate) mod types;
+pub(crate) mod virtio_net;
+pub(crate) mod virtio_types;
+pub(crate) mod watchdog;
+
+/// Directory: `linux/netfilter/`
+///
+/// <https://github.com/torvalds/linux/tree/master/include/uapi/linux/netfilter>
+pub(crate) mod netfilter {
+    pub(crate) mod nfnetlink;
+    pub(crate) mod nfnetlink_conntrack;
+    pub(crate) mod nfnetlink_log;
+    pub(crate) mod nfnetlink_queue;
+}
@@ -0,0 +1,88 @@
+//! Header: `uapi/linux/mptcp.h`
+
+use crate::prelude::*;
+use crate::{
+    __u32,
+    __u64,
+    __u8,
+};
+
+s! {
+    pub struct mptcp_info {
+        pub mptcpi_subflows: __u8,
+        pub mptcpi_add_addr_signal: __u8,
+        pub mptcpi_add_addr_accepted: __u8,
+        pub mptcpi_subflows_max: __u8,
+        pub mptcpi_add_addr_signal_max: __u8,
+        pub mptcpi_add_addr_accepted_max: __u8,
+        pub mptcpi_flags: __u32,
+        pub mptcpi_token: __u32,
+        pub mptcpi_write_seq: __u64,
+        pub mptcpi_snd_una: __u64,
+        pub mptcpi_rcv_nxt: __u64,
+        pub mptcpi_local_addr_used: __u8,
+        pub mptcpi_local_addr_max: __u8,
+        pub mptcpi_csum_enabled: __u8,
+        pub mptcpi_retransmits: __u32,
+        pub mptcpi_bytes_retrans: __u64,
+        pub mptcpi_bytes_sent: __u64,
+        pub mptcpi_bytes_received: __u64,
+        pub mptcpi_bytes_acked: __u64,
+        pub mptcpi_subflows_total: __u8,
+        reserved: Padding<[__u8; 3]>,
+        pub mptcpi_last_data_sent: __u32,
+        pub mptcpi_last_data_recv: __u32,
+        pub mptcpi_last_ack_recv: __u32,
+    }
+
+    #[repr(align(8))]
+    pub struct mptcp_subflow_data {
+        pub size_subflow_data: __u32,
+        pub num_subflows: __u32,
+        pub size_kernel: __u32,
+        pub size_user: __u32,
+    }
+
+    #[repr(align(8))]
+    pub struct mptcp_full_info {
+        pub size_tcpinfo_kernel: __u32,
+        pub size_tcpinfo_user: __u32,
+        pub size_sfinfo_kernel: __u32,
+        pub size_sfinfo_user: __u32,
+        pub num_subflows: __u32,
+        pub size_arrays_user: __u32,
+        pub subflow_info: __u64,
+        pub tcp_info: __u64,
+        pub mptcp_info: mptcp_info,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_mptcp_subflow_addrs_1 {
+        pub sa_family: crate::sa_family_t,
+        pub sa_local: crate::sockaddr,
+        pub sin_local: crate::sockaddr_in,
+        pub sin6_local: crate::sockaddr_in6,
+        pub ss_local: crate::sockaddr_storage,
+    }
+
+    pub union __c_anonymous_mptcp_subflow_addrs_2 {
+        pub sa_remote: crate::sockaddr,
+        pub sin_remote: crate::sockaddr_in,
+        pub sin6_remote: crate::sockaddr_in6,
+        pub ss_remote: crate::sockaddr_storage,
+    }
+
+    pub struct mptcp_subflow_addrs {
+        pub anonymous_1: __c_anonymous_mptcp_subflow_addrs_1,
+        pub anonymous_2: __c_anonymous_mptcp_subflow_addrs_2,
+    }
+}
+
+pub const MPTCP_INFO_FLAG_FALLBACK: c_uint = 1 << 0;
+pub const MPTCP_INFO_FLAG_REMOTE_KEY_RECEIVED: c_uint = 1 << 1;
+
+pub const MPTCP_INFO: c_int = 1;
+pub const MPTCP_TCPINFO: c_int = 2;
+pub const MPTCP_SUBFLOW_ADDRS: c_int = 3;
+pub const MPTCP_FULL_INFO: c_int = 4;
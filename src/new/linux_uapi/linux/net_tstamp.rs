@@ -0,0 +1,83 @@
+//! Header: `uapi/linux/net_tstamp.h`
+
+use crate::__u32;
+use crate::prelude::*;
+
+s! {
+    pub struct so_timestamping {
+        pub flags: c_int,
+        pub bind_phc: c_int,
+    }
+
+    pub struct hwtstamp_config {
+        pub flags: c_int,
+        pub tx_type: c_int,
+        pub rx_filter: c_int,
+    }
+
+    pub struct scm_ts_pktinfo {
+        pub if_index: __u32,
+        pub pkt_length: __u32,
+        reserved: Padding<[__u32; 2]>,
+    }
+
+    pub struct sock_txtime {
+        pub clockid: crate::clockid_t,
+        pub flags: __u32,
+    }
+}
+
+pub const SOF_TIMESTAMPING_TX_HARDWARE: c_uint = 1 << 0;
+pub const SOF_TIMESTAMPING_TX_SOFTWARE: c_uint = 1 << 1;
+pub const SOF_TIMESTAMPING_RX_HARDWARE: c_uint = 1 << 2;
+pub const SOF_TIMESTAMPING_RX_SOFTWARE: c_uint = 1 << 3;
+pub const SOF_TIMESTAMPING_SOFTWARE: c_uint = 1 << 4;
+pub const SOF_TIMESTAMPING_SYS_HARDWARE: c_uint = 1 << 5;
+pub const SOF_TIMESTAMPING_RAW_HARDWARE: c_uint = 1 << 6;
+pub const SOF_TIMESTAMPING_OPT_ID: c_uint = 1 << 7;
+pub const SOF_TIMESTAMPING_TX_SCHED: c_uint = 1 << 8;
+pub const SOF_TIMESTAMPING_TX_ACK: c_uint = 1 << 9;
+pub const SOF_TIMESTAMPING_OPT_CMSG: c_uint = 1 << 10;
+pub const SOF_TIMESTAMPING_OPT_TSONLY: c_uint = 1 << 11;
+pub const SOF_TIMESTAMPING_OPT_STATS: c_uint = 1 << 12;
+pub const SOF_TIMESTAMPING_OPT_PKTINFO: c_uint = 1 << 13;
+pub const SOF_TIMESTAMPING_OPT_TX_SWHW: c_uint = 1 << 14;
+pub const SOF_TIMESTAMPING_BIND_PHC: c_uint = 1 << 15;
+pub const SOF_TIMESTAMPING_OPT_ID_TCP: c_uint = 1 << 16;
+pub const SOF_TIMESTAMPING_OPT_RX_FILTER: c_uint = 1 << 17;
+pub const SOF_TIMESTAMPING_TX_COMPLETION: c_uint = 1 << 18;
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const SOF_TIMESTAMPING_TX_RECORD_MASK: c_uint = SOF_TIMESTAMPING_TX_HARDWARE
+    | SOF_TIMESTAMPING_TX_SOFTWARE
+    | SOF_TIMESTAMPING_TX_SCHED
+    | SOF_TIMESTAMPING_TX_ACK
+    | SOF_TIMESTAMPING_TX_COMPLETION;
+
+pub const HWTSTAMP_FLAG_BONDED_PHC_INDEX: c_uint = 1 << 0;
+
+pub const HWTSTAMP_TX_OFF: c_uint = 0;
+pub const HWTSTAMP_TX_ON: c_uint = 1;
+pub const HWTSTAMP_TX_ONESTEP_SYNC: c_uint = 2;
+pub const HWTSTAMP_TX_ONESTEP_P2P: c_uint = 3;
+
+pub const HWTSTAMP_FILTER_NONE: c_uint = 0;
+pub const HWTSTAMP_FILTER_ALL: c_uint = 1;
+pub const HWTSTAMP_FILTER_SOME: c_uint = 2;
+pub const HWTSTAMP_FILTER_PTP_V1_L4_EVENT: c_uint = 3;
+pub const HWTSTAMP_FILTER_PTP_V1_L4_SYNC: c_uint = 4;
+pub const HWTSTAMP_FILTER_PTP_V1_L4_DELAY_REQ: c_uint = 5;
+pub const HWTSTAMP_FILTER_PTP_V2_L4_EVENT: c_uint = 6;
+pub const HWTSTAMP_FILTER_PTP_V2_L4_SYNC: c_uint = 7;
+pub const HWTSTAMP_FILTER_PTP_V2_L4_DELAY_REQ: c_uint = 8;
+pub const HWTSTAMP_FILTER_PTP_V2_L2_EVENT: c_uint = 9;
+pub const HWTSTAMP_FILTER_PTP_V2_L2_SYNC: c_uint = 10;
+pub const HWTSTAMP_FILTER_PTP_V2_L2_DELAY_REQ: c_uint = 11;
+pub const HWTSTAMP_FILTER_PTP_V2_EVENT: c_uint = 12;
+pub const HWTSTAMP_FILTER_PTP_V2_SYNC: c_uint = 13;
+pub const HWTSTAMP_FILTER_PTP_V2_DELAY_REQ: c_uint = 14;
+pub const HWTSTAMP_FILTER_NTP_ALL: c_uint = 15;
+
+pub const SOF_TXTIME_DEADLINE_MODE: u32 = 1 << 0;
+pub const SOF_TXTIME_REPORT_ERRORS: u32 = 1 << 1;
@@ -0,0 +1,62 @@
+//! Header: `uapi/linux/netfilter/nfnetlink.h`
+
+use crate::prelude::*;
+use crate::{
+    __be16,
+    __u8,
+};
+
+s! {
+    pub struct nfgenmsg {
+        pub nfgen_family: __u8,
+        pub version: __u8,
+        pub res_id: __be16,
+    }
+}
+
+pub const NFNLGRP_NONE: c_int = 0;
+pub const NFNLGRP_CONNTRACK_NEW: c_int = 1;
+pub const NFNLGRP_CONNTRACK_UPDATE: c_int = 2;
+pub const NFNLGRP_CONNTRACK_DESTROY: c_int = 3;
+pub const NFNLGRP_CONNTRACK_EXP_NEW: c_int = 4;
+pub const NFNLGRP_CONNTRACK_EXP_UPDATE: c_int = 5;
+pub const NFNLGRP_CONNTRACK_EXP_DESTROY: c_int = 6;
+pub const NFNLGRP_NFTABLES: c_int = 7;
+pub const NFNLGRP_ACCT_QUOTA: c_int = 8;
+pub const NFNLGRP_NFTRACE: c_int = 9;
+
+pub const NFNETLINK_V0: c_int = 0;
+
+f! {
+    pub const safe fn NFNL_SUBSYS_ID(x: c_int) -> c_int {
+        (x & 0xff00) >> 8
+    }
+
+    pub const safe fn NFNL_MSG_TYPE(x: c_int) -> c_int {
+        x & 0x00ff
+    }
+}
+
+pub const NFNL_SUBSYS_NONE: c_int = 0;
+pub const NFNL_SUBSYS_CTNETLINK: c_int = 1;
+pub const NFNL_SUBSYS_CTNETLINK_EXP: c_int = 2;
+pub const NFNL_SUBSYS_QUEUE: c_int = 3;
+pub const NFNL_SUBSYS_ULOG: c_int = 4;
+pub const NFNL_SUBSYS_OSF: c_int = 5;
+pub const NFNL_SUBSYS_IPSET: c_int = 6;
+pub const NFNL_SUBSYS_ACCT: c_int = 7;
+pub const NFNL_SUBSYS_CTNETLINK_TIMEOUT: c_int = 8;
+pub const NFNL_SUBSYS_CTHELPER: c_int = 9;
+pub const NFNL_SUBSYS_NFTABLES: c_int = 10;
+pub const NFNL_SUBSYS_NFT_COMPAT: c_int = 11;
+pub const NFNL_SUBSYS_HOOK: c_int = 12;
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const NFNL_SUBSYS_COUNT: c_int = 13;
+
+pub const NFNL_MSG_BATCH_BEGIN: c_int = crate::NLMSG_MIN_TYPE;
+pub const NFNL_MSG_BATCH_END: c_int = crate::NLMSG_MIN_TYPE + 1;
+
+pub const NFNL_BATCH_UNSPEC: c_int = 0;
+pub const NFNL_BATCH_GENID: c_int = 1;
@@ -0,0 +1,265 @@
+//! Header: `uapi/linux/netfilter/nfnetlink_conntrack.h`
+
+use crate::prelude::*;
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum #anon {
+        pub IPCTNL_MSG_CT_NEW,
+        pub IPCTNL_MSG_CT_GET,
+        pub IPCTNL_MSG_CT_DELETE,
+        pub IPCTNL_MSG_CT_GET_CTRZERO,
+        pub IPCTNL_MSG_CT_GET_STATS_CPU,
+        pub IPCTNL_MSG_CT_GET_STATS,
+        pub IPCTNL_MSG_CT_GET_DYING,
+        pub IPCTNL_MSG_CT_GET_UNCONFIRMED,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub IPCTNL_MSG_EXP_NEW,
+        pub IPCTNL_MSG_EXP_GET,
+        pub IPCTNL_MSG_EXP_DELETE,
+        pub IPCTNL_MSG_EXP_GET_STATS_CPU,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_UNSPEC,
+        pub CTA_TUPLE_ORIG,
+        pub CTA_TUPLE_REPLY,
+        pub CTA_STATUS,
+        pub CTA_PROTOINFO,
+        pub CTA_HELP,
+        pub CTA_NAT_SRC,
+        pub CTA_TIMEOUT,
+        pub CTA_MARK,
+        pub CTA_COUNTERS_ORIG,
+        pub CTA_COUNTERS_REPLY,
+        pub CTA_USE,
+        pub CTA_ID,
+        pub CTA_NAT_DST,
+        pub CTA_TUPLE_MASTER,
+        pub CTA_SEQ_ADJ_ORIG,
+        pub CTA_NAT_SEQ_ADJ_ORIG = CTA_SEQ_ADJ_ORIG,
+        pub CTA_SEQ_ADJ_REPLY,
+        pub CTA_NAT_SEQ_ADJ_REPLY = CTA_SEQ_ADJ_REPLY,
+        pub CTA_SECMARK,
+        pub CTA_ZONE,
+        pub CTA_SECCTX,
+        pub CTA_TIMESTAMP,
+        pub CTA_MARK_MASK,
+        pub CTA_LABELS,
+        pub CTA_LABELS_MASK,
+        pub CTA_SYNPROXY,
+        pub CTA_FILTER,
+        pub CTA_STATUS_MASK,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_TUPLE_UNSPEC,
+        pub CTA_TUPLE_IP,
+        pub CTA_TUPLE_PROTO,
+        pub CTA_TUPLE_ZONE,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_IP_UNSPEC,
+        pub CTA_IP_V4_SRC,
+        pub CTA_IP_V4_DST,
+        pub CTA_IP_V6_SRC,
+        pub CTA_IP_V6_DST,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_PROTO_UNSPEC,
+        pub CTA_PROTO_NUM,
+        pub CTA_PROTO_SRC_PORT,
+        pub CTA_PROTO_DST_PORT,
+        pub CTA_PROTO_ICMP_ID,
+        pub CTA_PROTO_ICMP_TYPE,
+        pub CTA_PROTO_ICMP_CODE,
+        pub CTA_PROTO_ICMPV6_ID,
+        pub CTA_PROTO_ICMPV6_TYPE,
+        pub CTA_PROTO_ICMPV6_CODE,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_PROTOINFO_UNSPEC,
+        pub CTA_PROTOINFO_TCP,
+        pub CTA_PROTOINFO_DCCP,
+        pub CTA_PROTOINFO_SCTP,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_PROTOINFO_TCP_UNSPEC,
+        pub CTA_PROTOINFO_TCP_STATE,
+        pub CTA_PROTOINFO_TCP_WSCALE_ORIGINAL,
+        pub CTA_PROTOINFO_TCP_WSCALE_REPLY,
+        pub CTA_PROTOINFO_TCP_FLAGS_ORIGINAL,
+        pub CTA_PROTOINFO_TCP_FLAGS_REPLY,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_PROTOINFO_DCCP_UNSPEC,
+        pub CTA_PROTOINFO_DCCP_STATE,
+        pub CTA_PROTOINFO_DCCP_ROLE,
+        pub CTA_PROTOINFO_DCCP_HANDSHAKE_SEQ,
+        pub CTA_PROTOINFO_DCCP_PAD,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_PROTOINFO_SCTP_UNSPEC,
+        pub CTA_PROTOINFO_SCTP_STATE,
+        pub CTA_PROTOINFO_SCTP_VTAG_ORIGINAL,
+        pub CTA_PROTOINFO_SCTP_VTAG_REPLY,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_COUNTERS_UNSPEC,
+        pub CTA_COUNTERS_PACKETS,
+        pub CTA_COUNTERS_BYTES,
+        pub CTA_COUNTERS32_PACKETS,
+        pub CTA_COUNTERS32_BYTES,
+        pub CTA_COUNTERS_PAD,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_TIMESTAMP_UNSPEC,
+        pub CTA_TIMESTAMP_START,
+        pub CTA_TIMESTAMP_STOP,
+        pub CTA_TIMESTAMP_PAD,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_NAT_UNSPEC,
+        pub CTA_NAT_V4_MINIP,
+        pub CTA_NAT_V4_MAXIP,
+        pub CTA_NAT_PROTO,
+        pub CTA_NAT_V6_MINIP,
+        pub CTA_NAT_V6_MAXIP,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_PROTONAT_UNSPEC,
+        pub CTA_PROTONAT_PORT_MIN,
+        pub CTA_PROTONAT_PORT_MAX,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_SEQADJ_UNSPEC,
+        pub CTA_SEQADJ_CORRECTION_POS,
+        pub CTA_SEQADJ_OFFSET_BEFORE,
+        pub CTA_SEQADJ_OFFSET_AFTER,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_NAT_SEQ_UNSPEC,
+        pub CTA_NAT_SEQ_CORRECTION_POS,
+        pub CTA_NAT_SEQ_OFFSET_BEFORE,
+        pub CTA_NAT_SEQ_OFFSET_AFTER,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_SYNPROXY_UNSPEC,
+        pub CTA_SYNPROXY_ISN,
+        pub CTA_SYNPROXY_ITS,
+        pub CTA_SYNPROXY_TSOFF,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_EXPECT_UNSPEC,
+        pub CTA_EXPECT_MASTER,
+        pub CTA_EXPECT_TUPLE,
+        pub CTA_EXPECT_MASK,
+        pub CTA_EXPECT_TIMEOUT,
+        pub CTA_EXPECT_ID,
+        pub CTA_EXPECT_HELP_NAME,
+        pub CTA_EXPECT_ZONE,
+        pub CTA_EXPECT_FLAGS,
+        pub CTA_EXPECT_CLASS,
+        pub CTA_EXPECT_NAT,
+        pub CTA_EXPECT_FN,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_EXPECT_NAT_UNSPEC,
+        pub CTA_EXPECT_NAT_DIR,
+        pub CTA_EXPECT_NAT_TUPLE,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_HELP_UNSPEC,
+        pub CTA_HELP_NAME,
+        pub CTA_HELP_INFO,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_SECCTX_UNSPEC,
+        pub CTA_SECCTX_NAME,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_STATS_UNSPEC,
+        pub CTA_STATS_SEARCHED,
+        pub CTA_STATS_FOUND,
+        pub CTA_STATS_NEW,
+        pub CTA_STATS_INVALID,
+        pub CTA_STATS_IGNORE,
+        pub CTA_STATS_DELETE,
+        pub CTA_STATS_DELETE_LIST,
+        pub CTA_STATS_INSERT,
+        pub CTA_STATS_INSERT_FAILED,
+        pub CTA_STATS_DROP,
+        pub CTA_STATS_EARLY_DROP,
+        pub CTA_STATS_ERROR,
+        pub CTA_STATS_SEARCH_RESTART,
+        pub CTA_STATS_CLASH_RESOLVE,
+        pub CTA_STATS_CHAIN_TOOLONG,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_STATS_GLOBAL_UNSPEC,
+        pub CTA_STATS_GLOBAL_ENTRIES,
+        pub CTA_STATS_GLOBAL_MAX_ENTRIES,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_STATS_EXP_UNSPEC,
+        pub CTA_STATS_EXP_NEW,
+        pub CTA_STATS_EXP_CREATE,
+        pub CTA_STATS_EXP_DELETE,
+    }
+
+    #[repr(c_int)]
+    pub enum #anon {
+        pub CTA_FILTER_UNSPEC,
+        pub CTA_FILTER_ORIG_FLAGS,
+        pub CTA_FILTER_REPLY_FLAGS,
+    }
+}
+
+pub const CTA_NAT: c_int = CTA_NAT_SRC;
+pub const CTA_NAT_MINIP: c_int = CTA_NAT_V4_MINIP;
+pub const CTA_NAT_MAXIP: c_int = CTA_NAT_V4_MAXIP;
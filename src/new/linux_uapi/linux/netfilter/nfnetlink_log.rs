@@ -0,0 +1,94 @@
+//! Header: `uapi/linux/netfilter/nfnetlink_log.h`
+
+use crate::prelude::*;
+use crate::{
+    __be16,
+    __be32,
+    __be64,
+    __u16,
+    __u8,
+};
+
+s! {
+    pub struct nfulnl_msg_packet_hdr {
+        pub hw_protocol: __be16,
+        pub hook: __u8,
+        _pad: Padding<__u8>,
+    }
+
+    pub struct nfulnl_msg_packet_hw {
+        pub hw_addrlen: __be16,
+        _pad: Padding<__u16>,
+        pub hw_addr: [__u8; 8],
+    }
+
+    #[repr(align(8))]
+    pub struct nfulnl_msg_packet_timestamp {
+        pub sec: __be64,
+        pub usec: __be64,
+    }
+
+    #[repr(packed)]
+    pub struct nfulnl_msg_config_cmd {
+        pub command: __u8,
+    }
+
+    #[repr(packed)]
+    pub struct nfulnl_msg_config_mode {
+        pub copy_range: __be32,
+        pub copy_mode: __u8,
+        _pad: Padding<__u8>,
+    }
+}
+
+pub const NFULNL_MSG_PACKET: c_int = 0;
+pub const NFULNL_MSG_CONFIG: c_int = 1;
+
+pub const NFULA_VLAN_UNSPEC: c_int = 0;
+pub const NFULA_VLAN_PROTO: c_int = 1;
+pub const NFULA_VLAN_TCI: c_int = 2;
+
+pub const NFULA_UNSPEC: c_int = 0;
+pub const NFULA_PACKET_HDR: c_int = 1;
+pub const NFULA_MARK: c_int = 2;
+pub const NFULA_TIMESTAMP: c_int = 3;
+pub const NFULA_IFINDEX_INDEV: c_int = 4;
+pub const NFULA_IFINDEX_OUTDEV: c_int = 5;
+pub const NFULA_IFINDEX_PHYSINDEV: c_int = 6;
+pub const NFULA_IFINDEX_PHYSOUTDEV: c_int = 7;
+pub const NFULA_HWADDR: c_int = 8;
+pub const NFULA_PAYLOAD: c_int = 9;
+pub const NFULA_PREFIX: c_int = 10;
+pub const NFULA_UID: c_int = 11;
+pub const NFULA_SEQ: c_int = 12;
+pub const NFULA_SEQ_GLOBAL: c_int = 13;
+pub const NFULA_GID: c_int = 14;
+pub const NFULA_HWTYPE: c_int = 15;
+pub const NFULA_HWHEADER: c_int = 16;
+pub const NFULA_HWLEN: c_int = 17;
+pub const NFULA_CT: c_int = 18;
+pub const NFULA_CT_INFO: c_int = 19;
+pub const NFULA_VLAN: c_int = 20;
+pub const NFULA_L2HDR: c_int = 21;
+
+pub const NFULNL_CFG_CMD_NONE: c_int = 0;
+pub const NFULNL_CFG_CMD_BIND: c_int = 1;
+pub const NFULNL_CFG_CMD_UNBIND: c_int = 2;
+pub const NFULNL_CFG_CMD_PF_BIND: c_int = 3;
+pub const NFULNL_CFG_CMD_PF_UNBIND: c_int = 4;
+
+pub const NFULA_CFG_UNSPEC: c_int = 0;
+pub const NFULA_CFG_CMD: c_int = 1;
+pub const NFULA_CFG_MODE: c_int = 2;
+pub const NFULA_CFG_NLBUFSIZ: c_int = 3;
+pub const NFULA_CFG_TIMEOUT: c_int = 4;
+pub const NFULA_CFG_QTHRESH: c_int = 5;
+pub const NFULA_CFG_FLAGS: c_int = 6;
+
+pub const NFULNL_COPY_NONE: c_int = 0x00;
+pub const NFULNL_COPY_META: c_int = 0x01;
+pub const NFULNL_COPY_PACKET: c_int = 0x02;
+
+pub const NFULNL_CFG_F_SEQ: c_int = 0x0001;
+pub const NFULNL_CFG_F_SEQ_GLOBAL: c_int = 0x0002;
+pub const NFULNL_CFG_F_CONNTRACK: c_int = 0x0004;
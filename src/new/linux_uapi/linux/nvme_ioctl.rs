@@ -0,0 +1,120 @@
+//! Header: `uapi/linux/nvme_ioctl.h`
+
+use crate::{
+    __u16,
+    __u32,
+    __u64,
+    __u8,
+    Ioctl,
+    _IO,
+    _IOW,
+    _IOWR,
+};
+
+s! {
+    pub struct nvme_user_io {
+        pub opcode: __u8,
+        pub flags: __u8,
+        pub control: __u16,
+        pub nblocks: __u16,
+        pub rsvd: __u16,
+        pub metadata: __u64,
+        pub addr: __u64,
+        pub slba: __u64,
+        pub dsmgmt: __u32,
+        pub reftag: __u32,
+        pub apptag: __u16,
+        pub appmask: __u16,
+    }
+
+    pub struct nvme_passthru_cmd {
+        pub opcode: __u8,
+        pub flags: __u8,
+        pub rsvd1: __u16,
+        pub nsid: __u32,
+        pub cdw2: __u32,
+        pub cdw3: __u32,
+        pub metadata: __u64,
+        pub addr: __u64,
+        pub metadata_len: __u32,
+        pub data_len: __u32,
+        pub cdw10: __u32,
+        pub cdw11: __u32,
+        pub cdw12: __u32,
+        pub cdw13: __u32,
+        pub cdw14: __u32,
+        pub cdw15: __u32,
+        pub timeout_ms: __u32,
+        pub result: __u32,
+    }
+
+    pub struct nvme_uring_cmd {
+        pub opcode: __u8,
+        pub flags: __u8,
+        pub rsvd1: __u16,
+        pub nsid: __u32,
+        pub cdw2: __u32,
+        pub cdw3: __u32,
+        pub metadata: __u64,
+        pub addr: __u64,
+        pub metadata_len: __u32,
+        pub data_len: __u32,
+        pub cdw10: __u32,
+        pub cdw11: __u32,
+        pub cdw12: __u32,
+        pub cdw13: __u32,
+        pub cdw14: __u32,
+        pub cdw15: __u32,
+        pub timeout_ms: __u32,
+        pub rsvd2: __u32,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_nvme_passthru_cmd64_1 {
+        pub data_len: __u32,
+        pub vec_cnt: __u32,
+    }
+
+    pub struct nvme_passthru_cmd64 {
+        pub opcode: __u8,
+        pub flags: __u8,
+        pub rsvd1: __u16,
+        pub nsid: __u32,
+        pub cdw2: __u32,
+        pub cdw3: __u32,
+        pub metadata: __u64,
+        pub addr: __u64,
+        pub metadata_len: __u32,
+        pub anonymous_1: __c_anonymous_nvme_passthru_cmd64_1,
+        pub cdw10: __u32,
+        pub cdw11: __u32,
+        pub cdw12: __u32,
+        pub cdw13: __u32,
+        pub cdw14: __u32,
+        pub cdw15: __u32,
+        pub timeout_ms: __u32,
+        pub rsvd2: __u32,
+        pub result: __u64,
+    }
+}
+
+pub type nvme_admin_cmd = nvme_passthru_cmd;
+
+const NVME_IOCTL_MAGIC: u32 = b'N' as u32;
+
+pub const NVME_IOCTL_ID: Ioctl = _IO(NVME_IOCTL_MAGIC, 0x40);
+pub const NVME_IOCTL_ADMIN_CMD: Ioctl = _IOWR::<nvme_admin_cmd>(NVME_IOCTL_MAGIC, 0x41);
+pub const NVME_IOCTL_SUBMIT_IO: Ioctl = _IOW::<nvme_user_io>(NVME_IOCTL_MAGIC, 0x42);
+pub const NVME_IOCTL_IO_CMD: Ioctl = _IOWR::<nvme_passthru_cmd>(NVME_IOCTL_MAGIC, 0x43);
+pub const NVME_IOCTL_RESET: Ioctl = _IO(NVME_IOCTL_MAGIC, 0x44);
+pub const NVME_IOCTL_SUBSYS_RESET: Ioctl = _IO(NVME_IOCTL_MAGIC, 0x45);
+pub const NVME_IOCTL_RESCAN: Ioctl = _IO(NVME_IOCTL_MAGIC, 0x46);
+pub const NVME_IOCTL_ADMIN64_CMD: Ioctl = _IOWR::<nvme_passthru_cmd64>(NVME_IOCTL_MAGIC, 0x47);
+pub const NVME_IOCTL_IO64_CMD: Ioctl = _IOWR::<nvme_passthru_cmd64>(NVME_IOCTL_MAGIC, 0x48);
+pub const NVME_IOCTL_IO64_CMD_VEC: Ioctl = _IOWR::<nvme_passthru_cmd64>(NVME_IOCTL_MAGIC, 0x49);
+
+pub const NVME_URING_CMD_IO: Ioctl = _IOWR::<nvme_uring_cmd>(NVME_IOCTL_MAGIC, 0x80);
+pub const NVME_URING_CMD_IO_VEC: Ioctl = _IOWR::<nvme_uring_cmd>(NVME_IOCTL_MAGIC, 0x81);
+pub const NVME_URING_CMD_ADMIN: Ioctl = _IOWR::<nvme_uring_cmd>(NVME_IOCTL_MAGIC, 0x82);
+pub const NVME_URING_CMD_ADMIN_VEC: Ioctl = _IOWR::<nvme_uring_cmd>(NVME_IOCTL_MAGIC, 0x83);
@@ -0,0 +1,57 @@
+//! Header: `uapi/linux/rseq.h`
+
+use crate::prelude::*;
+use crate::{
+    __u32,
+    __u64,
+};
+
+c_enum! {
+    #[repr(c_int)]
+    pub enum rseq_cpu_id_state {
+        pub RSEQ_CPU_ID_UNINITIALIZED = -1,
+        pub RSEQ_CPU_ID_REGISTRATION_FAILED = -2,
+    }
+
+    pub enum rseq_flags {
+        pub RSEQ_FLAG_UNREGISTER = 1 << 0,
+    }
+
+    pub enum rseq_cs_flags_bit {
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_PREEMPT_BIT = 0,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_SIGNAL_BIT = 1,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_MIGRATE_BIT = 2,
+    }
+
+    pub enum rseq_cs_flags {
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_PREEMPT = 1 << RSEQ_CS_FLAG_NO_RESTART_ON_PREEMPT_BIT,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_SIGNAL = 1 << RSEQ_CS_FLAG_NO_RESTART_ON_SIGNAL_BIT,
+        pub RSEQ_CS_FLAG_NO_RESTART_ON_MIGRATE = 1 << RSEQ_CS_FLAG_NO_RESTART_ON_MIGRATE_BIT,
+    }
+}
+
+s! {
+    /// Critical section descriptor, pointed to by [`rseq::rseq_cs`].
+    #[repr(align(32))]
+    pub struct rseq_cs {
+        pub version: __u32,
+        pub flags: __u32,
+        pub start_ip: __u64,
+        pub post_commit_offset: __u64,
+        pub abort_ip: __u64,
+    }
+
+    /// Per-thread area shared with the kernel.
+    ///
+    /// The kernel may extend this structure; the size actually in use is reported by
+    /// `getauxval(AT_RSEQ_FEATURE_SIZE)`.
+    #[repr(align(32))]
+    pub struct rseq {
+        pub cpu_id_start: __u32,
+        pub cpu_id: __u32,
+        pub rseq_cs: __u64,
+        pub flags: __u32,
+        pub node_id: __u32,
+        pub mm_cid: __u32,
+    }
+}
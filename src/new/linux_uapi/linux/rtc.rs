@@ -0,0 +1,123 @@
+//! Header: `uapi/linux/rtc.h`
+
+use crate::prelude::*;
+use crate::{
+    __s64,
+    __u32,
+    __u64,
+    Ioctl,
+    _IO,
+    _IOR,
+    _IOW,
+};
+
+s! {
+    pub struct rtc_time {
+        pub tm_sec: c_int,
+        pub tm_min: c_int,
+        pub tm_hour: c_int,
+        pub tm_mday: c_int,
+        pub tm_mon: c_int,
+        pub tm_year: c_int,
+        pub tm_wday: c_int,
+        pub tm_yday: c_int,
+        pub tm_isdst: c_int,
+    }
+
+    pub struct rtc_wkalrm {
+        pub enabled: c_uchar,
+        pub pending: c_uchar,
+        pub time: rtc_time,
+    }
+
+    pub struct rtc_pll_info {
+        pub pll_ctrl: c_int,
+        pub pll_value: c_int,
+        pub pll_max: c_int,
+        pub pll_min: c_int,
+        pub pll_posmult: c_int,
+        pub pll_negmult: c_int,
+        pub pll_clock: c_long,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_rtc_param_1 {
+        pub uvalue: __u64,
+        pub svalue: __s64,
+        pub ptr: __u64,
+    }
+
+    pub struct rtc_param {
+        pub param: __u64,
+        pub anonymous_1: __c_anonymous_rtc_param_1,
+        pub index: __u32,
+        __pad: Padding<__u32>,
+    }
+}
+
+const RTC_MAGIC: u32 = b'p' as u32;
+
+pub const RTC_AIE_ON: Ioctl = _IO(RTC_MAGIC, 0x01);
+pub const RTC_AIE_OFF: Ioctl = _IO(RTC_MAGIC, 0x02);
+pub const RTC_UIE_ON: Ioctl = _IO(RTC_MAGIC, 0x03);
+pub const RTC_UIE_OFF: Ioctl = _IO(RTC_MAGIC, 0x04);
+pub const RTC_PIE_ON: Ioctl = _IO(RTC_MAGIC, 0x05);
+pub const RTC_PIE_OFF: Ioctl = _IO(RTC_MAGIC, 0x06);
+pub const RTC_WIE_ON: Ioctl = _IO(RTC_MAGIC, 0x0f);
+pub const RTC_WIE_OFF: Ioctl = _IO(RTC_MAGIC, 0x10);
+
+pub const RTC_ALM_SET: Ioctl = _IOW::<rtc_time>(RTC_MAGIC, 0x07);
+pub const RTC_ALM_READ: Ioctl = _IOR::<rtc_time>(RTC_MAGIC, 0x08);
+pub const RTC_RD_TIME: Ioctl = _IOR::<rtc_time>(RTC_MAGIC, 0x09);
+pub const RTC_SET_TIME: Ioctl = _IOW::<rtc_time>(RTC_MAGIC, 0x0a);
+pub const RTC_IRQP_READ: Ioctl = _IOR::<c_ulong>(RTC_MAGIC, 0x0b);
+pub const RTC_IRQP_SET: Ioctl = _IOW::<c_ulong>(RTC_MAGIC, 0x0c);
+pub const RTC_EPOCH_READ: Ioctl = _IOR::<c_ulong>(RTC_MAGIC, 0x0d);
+pub const RTC_EPOCH_SET: Ioctl = _IOW::<c_ulong>(RTC_MAGIC, 0x0e);
+
+pub const RTC_WKALM_SET: Ioctl = _IOW::<rtc_wkalrm>(RTC_MAGIC, 0x0f);
+pub const RTC_WKALM_RD: Ioctl = _IOR::<rtc_wkalrm>(RTC_MAGIC, 0x10);
+
+pub const RTC_PLL_GET: Ioctl = _IOR::<rtc_pll_info>(RTC_MAGIC, 0x11);
+pub const RTC_PLL_SET: Ioctl = _IOW::<rtc_pll_info>(RTC_MAGIC, 0x12);
+
+pub const RTC_PARAM_GET: Ioctl = _IOW::<rtc_param>(RTC_MAGIC, 0x13);
+pub const RTC_PARAM_SET: Ioctl = _IOW::<rtc_param>(RTC_MAGIC, 0x14);
+
+pub const RTC_VL_DATA_INVALID: c_uint = 1 << 0;
+pub const RTC_VL_BACKUP_LOW: c_uint = 1 << 1;
+pub const RTC_VL_BACKUP_EMPTY: c_uint = 1 << 2;
+pub const RTC_VL_ACCURACY_LOW: c_uint = 1 << 3;
+pub const RTC_VL_BACKUP_SWITCH: c_uint = 1 << 4;
+
+pub const RTC_VL_READ: Ioctl = _IOR::<c_uint>(RTC_MAGIC, 0x13);
+pub const RTC_VL_CLR: Ioctl = _IO(RTC_MAGIC, 0x14);
+
+pub const RTC_IRQF: c_int = 0x80;
+pub const RTC_PF: c_int = 0x40;
+pub const RTC_AF: c_int = 0x20;
+pub const RTC_UF: c_int = 0x10;
+
+pub const RTC_FEATURE_ALARM: c_int = 0;
+pub const RTC_FEATURE_ALARM_RES_MINUTE: c_int = 1;
+pub const RTC_FEATURE_NEED_WEEK_DAY: c_int = 2;
+pub const RTC_FEATURE_ALARM_RES_2S: c_int = 3;
+pub const RTC_FEATURE_UPDATE_INTERRUPT: c_int = 4;
+pub const RTC_FEATURE_CORRECTION: c_int = 5;
+pub const RTC_FEATURE_BACKUP_SWITCH_MODE: c_int = 6;
+pub const RTC_FEATURE_ALARM_WAKEUP_ONLY: c_int = 7;
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const RTC_FEATURE_CNT: c_int = 8;
+
+pub const RTC_PARAM_FEATURES: c_int = 0;
+pub const RTC_PARAM_CORRECTION: c_int = 1;
+pub const RTC_PARAM_BACKUP_SWITCH_MODE: c_int = 2;
+
+pub const RTC_BSM_DISABLED: c_int = 0;
+pub const RTC_BSM_DIRECT: c_int = 1;
+pub const RTC_BSM_LEVEL: c_int = 2;
+pub const RTC_BSM_STANDBY: c_int = 3;
+
+pub const RTC_MAX_FREQ: c_int = 8192;
@@ -0,0 +1,116 @@
+//! Header: `uapi/linux/tcp.h`
+
+use crate::prelude::*;
+use crate::{
+    __be32,
+    __s32,
+    __u16,
+    __u32,
+    __u64,
+    __u8,
+};
+
+s! {
+    pub struct tcp_repair_opt {
+        pub opt_code: __u32,
+        pub opt_val: __u32,
+    }
+
+    pub struct tcp_repair_window {
+        pub snd_wl1: __u32,
+        pub snd_wnd: __u32,
+        pub max_window: __u32,
+        pub rcv_wnd: __u32,
+        pub rcv_wup: __u32,
+    }
+
+    pub struct tcp_md5sig {
+        pub tcpm_addr: crate::sockaddr_storage,
+        pub tcpm_flags: __u8,
+        pub tcpm_prefixlen: __u8,
+        pub tcpm_keylen: __u16,
+        pub tcpm_ifindex: c_int,
+        pub tcpm_key: [__u8; crate::TCP_MD5SIG_MAXKEYLEN],
+    }
+
+    pub struct tcp_diag_md5sig {
+        pub tcpm_family: __u8,
+        pub tcpm_prefixlen: __u8,
+        pub tcpm_keylen: __u16,
+        pub tcpm_addr: [__be32; 4],
+        pub tcpm_key: [__u8; crate::TCP_MD5SIG_MAXKEYLEN],
+    }
+
+    pub struct tcp_zerocopy_receive {
+        pub address: __u64,
+        pub length: __u32,
+        pub recv_skip_hint: __u32,
+        pub inq: __u32,
+        pub err: __s32,
+        pub copybuf_address: __u64,
+        pub copybuf_len: __s32,
+        pub flags: __u32,
+        pub msg_control: __u64,
+        pub msg_controllen: __u64,
+        pub msg_flags: __u32,
+        reserved: Padding<__u32>,
+    }
+
+    #[repr(align(8))]
+    pub struct tcp_ao_add {
+        pub addr: crate::sockaddr_storage,
+        pub alg_name: [c_char; 64],
+        pub ifindex: __s32,
+        /// This contains the bitfields `set_current` (1 bit), `set_rnext` (1 bit) and a
+        /// reserved 30 bits that must be zero.
+        pub bitfields: __u32,
+        reserved2: Padding<__u16>,
+        pub prefix: __u8,
+        pub sndid: __u8,
+        pub rcvid: __u8,
+        pub maclen: __u8,
+        pub keyflags: __u8,
+        pub keylen: __u8,
+        pub key: [__u8; TCP_AO_MAXKEYLEN],
+    }
+
+    #[repr(align(8))]
+    pub struct tcp_ao_info_opt {
+        /// This contains the bitfields `set_current`, `set_rnext`, `ao_required`,
+        /// `set_counters` and `accept_icmps` (1 bit each) and a reserved 27 bits that must be
+        /// zero.
+        pub bitfields: __u32,
+        reserved2: Padding<__u16>,
+        pub current_key: __u8,
+        pub rnext: __u8,
+        pub pkt_good: __u64,
+        pub pkt_bad: __u64,
+        pub pkt_key_not_found: __u64,
+        pub pkt_ao_required: __u64,
+        pub pkt_dropped_icmp: __u64,
+    }
+}
+
+pub const TCP_AO_ADD_KEY: c_int = 38;
+pub const TCP_AO_DEL_KEY: c_int = 39;
+pub const TCP_AO_INFO: c_int = 40;
+pub const TCP_AO_GET_KEYS: c_int = 41;
+pub const TCP_AO_REPAIR: c_int = 42;
+
+pub const TCP_REPAIR_ON: c_int = 1;
+pub const TCP_REPAIR_OFF: c_int = 0;
+pub const TCP_REPAIR_OFF_NO_WP: c_int = -1;
+
+pub const TCP_NO_QUEUE: c_int = 0;
+pub const TCP_RECV_QUEUE: c_int = 1;
+pub const TCP_SEND_QUEUE: c_int = 2;
+
+pub const TCP_MD5SIG_FLAG_PREFIX: __u8 = 0x1;
+pub const TCP_MD5SIG_FLAG_IFINDEX: __u8 = 0x2;
+
+pub const TCP_AO_MAXKEYLEN: usize = 80;
+
+pub const TCP_AO_KEYF_IFINDEX: __u8 = 1 << 0;
+pub const TCP_AO_KEYF_EXCLUDE_OPT: __u8 = 1 << 1;
+
+pub const TCP_RECEIVE_ZEROCOPY_FLAG_TLB_CLEAN_HINT: __u32 = 0x1;
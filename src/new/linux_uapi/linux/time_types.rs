@@ -0,0 +1,16 @@
+//! Header: `uapi/linux/time_types.h`
+
+use crate::__s64;
+use crate::prelude::*;
+
+s! {
+    pub struct __kernel_timespec {
+        pub tv_sec: crate::__kernel_time64_t,
+        pub tv_nsec: c_longlong,
+    }
+
+    pub struct __kernel_sock_timeval {
+        pub tv_sec: __s64,
+        pub tv_usec: __s64,
+    }
+}
@@ -3,8 +3,9 @@
 
 use crate::prelude::*;
 
-/* Definitions from `asm/types.h` -> `asm-generic/types.h` -> `asm-generic/int-ll64.h` */
+// Definitions from `asm/types.h` -> `asm-generic/types.h` -> `asm-generic/int-ll64.h`
 
+pub type __s8 = crate::c_schar;
 pub type __u8 = c_uchar;
 
 pub type __u16 = c_ushort;
@@ -15,3 +16,12 @@ pub type __s32 = c_int;
 
 pub type __s64 = c_longlong;
 pub type __u64 = c_ulonglong;
+
+// From `uapi/linux/types.h`
+
+pub type __le16 = __u16;
+pub type __be16 = __u16;
+pub type __le32 = __u32;
+pub type __be32 = __u32;
+pub type __le64 = __u64;
+pub type __be64 = __u64;
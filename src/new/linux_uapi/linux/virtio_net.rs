@@ -0,0 +1,80 @@
+//! Header: `uapi/linux/virtio_net.h`
+
+pub use crate::linux::virtio_types::*;
+use crate::prelude::*;
+use crate::{
+    __le16,
+    __le32,
+    __u8,
+};
+
+pub const VIRTIO_NET_HDR_F_NEEDS_CSUM: __u8 = 1;
+pub const VIRTIO_NET_HDR_F_DATA_VALID: __u8 = 2;
+pub const VIRTIO_NET_HDR_F_RSC_INFO: __u8 = 4;
+
+pub const VIRTIO_NET_HDR_GSO_NONE: __u8 = 0;
+pub const VIRTIO_NET_HDR_GSO_TCPV4: __u8 = 1;
+pub const VIRTIO_NET_HDR_GSO_UDP: __u8 = 3;
+pub const VIRTIO_NET_HDR_GSO_TCPV6: __u8 = 4;
+pub const VIRTIO_NET_HDR_GSO_UDP_L4: __u8 = 5;
+pub const VIRTIO_NET_HDR_GSO_ECN: __u8 = 0x80;
+
+s! {
+    pub struct __c_anonymous_virtio_net_hdr_v1_csum {
+        pub start: __virtio16,
+        pub offset: __virtio16,
+    }
+
+    pub struct __c_anonymous_virtio_net_hdr_v1_rsc {
+        pub segments: __le16,
+        pub dup_acks: __le16,
+    }
+
+    pub struct virtio_net_hdr {
+        pub flags: __u8,
+        pub gso_type: __u8,
+        pub hdr_len: __virtio16,
+        pub gso_size: __virtio16,
+        pub csum_start: __virtio16,
+        pub csum_offset: __virtio16,
+    }
+
+    pub struct virtio_net_hdr_mrg_rxbuf {
+        pub hdr: virtio_net_hdr,
+        pub num_buffers: __virtio16,
+    }
+}
+
+s_no_extra_traits! {
+    pub union __c_anonymous_virtio_net_hdr_v1_1 {
+        pub csum: __c_anonymous_virtio_net_hdr_v1_csum,
+        pub rsc: __c_anonymous_virtio_net_hdr_v1_rsc,
+    }
+
+    pub struct virtio_net_hdr_v1 {
+        pub flags: __u8,
+        pub gso_type: __u8,
+        pub hdr_len: __virtio16,
+        pub gso_size: __virtio16,
+        pub anonymous_1: __c_anonymous_virtio_net_hdr_v1_1,
+        pub num_buffers: __virtio16,
+    }
+
+    pub struct virtio_net_hdr_v1_hash {
+        pub hdr: virtio_net_hdr_v1,
+        pub hash_value: __le32,
+        pub hash_report: __le16,
+        padding: Padding<__le16>,
+    }
+}
+
+pub const VIRTIO_NET_HASH_REPORT_NONE: __le16 = 0;
+pub const VIRTIO_NET_HASH_REPORT_IPv4: __le16 = 1;
+pub const VIRTIO_NET_HASH_REPORT_TCPv4: __le16 = 2;
+pub const VIRTIO_NET_HASH_REPORT_UDPv4: __le16 = 3;
+pub const VIRTIO_NET_HASH_REPORT_IPv6: __le16 = 4;
+pub const VIRTIO_NET_HASH_REPORT_TCPv6: __le16 = 5;
+pub const VIRTIO_NET_HASH_REPORT_UDPv6: __le16 = 6;
+pub const VIRTIO_NET_HASH_REPORT_IPv6_EX: __le16 = 7;
+pub const VIRTIO_NET_HASH_REPORT_TCPv6_EX: __le16 = 8;
+pub const VIRTIO_NET_HASH_REPORT_UDPv6_EX: __le16 = 9;
@@ -0,0 +1,11 @@
+//! Header: `uapi/linux/virtio_types.h`
+
+use crate::{
+    __u16,
+    __u32,
+    __u64,
+};
+
+pub type __virtio16 = __u16;
+pub type __virtio32 = __u32;
+pub type __virtio64 = __u64;
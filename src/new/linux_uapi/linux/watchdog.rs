@@ -0,0 +1,52 @@
+//! Header: `uapi/linux/watchdog.h`
+
+use crate::prelude::*;
+use crate::{
+    __u32,
+    __u8,
+    Ioctl,
+    _IOR,
+    _IOWR,
+};
+
+const WATCHDOG_IOCTL_BASE: u32 = b'W' as u32;
+
+s! {
+    pub struct watchdog_info {
+        pub options: __u32,
+        pub firmware_version: __u32,
+        pub identity: [__u8; 32],
+    }
+}
+
+pub const WDIOC_GETSUPPORT: Ioctl = _IOR::<watchdog_info>(WATCHDOG_IOCTL_BASE, 0);
+pub const WDIOC_GETSTATUS: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 1);
+pub const WDIOC_GETBOOTSTATUS: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 2);
+pub const WDIOC_GETTEMP: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 3);
+pub const WDIOC_SETOPTIONS: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 4);
+pub const WDIOC_KEEPALIVE: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 5);
+pub const WDIOC_SETTIMEOUT: Ioctl = _IOWR::<c_int>(WATCHDOG_IOCTL_BASE, 6);
+pub const WDIOC_GETTIMEOUT: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 7);
+pub const WDIOC_SETPRETIMEOUT: Ioctl = _IOWR::<c_int>(WATCHDOG_IOCTL_BASE, 8);
+pub const WDIOC_GETPRETIMEOUT: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 9);
+pub const WDIOC_GETTIMELEFT: Ioctl = _IOR::<c_int>(WATCHDOG_IOCTL_BASE, 10);
+
+pub const WDIOF_UNKNOWN: c_int = -1;
+pub const WDIOS_UNKNOWN: c_int = -1;
+
+pub const WDIOF_OVERHEAT: c_int = 0x0001;
+pub const WDIOF_FANFAULT: c_int = 0x0002;
+pub const WDIOF_EXTERN1: c_int = 0x0004;
+pub const WDIOF_EXTERN2: c_int = 0x0008;
+pub const WDIOF_POWERUNDER: c_int = 0x0010;
+pub const WDIOF_CARDRESET: c_int = 0x0020;
+pub const WDIOF_POWEROVER: c_int = 0x0040;
+pub const WDIOF_SETTIMEOUT: c_int = 0x0080;
+pub const WDIOF_MAGICCLOSE: c_int = 0x0100;
+pub const WDIOF_PRETIMEOUT: c_int = 0x0200;
+pub const WDIOF_ALARMONLY: c_int = 0x0400;
+pub const WDIOF_KEEPALIVEPING: c_int = 0x8000;
+
+pub const WDIOS_DISABLECARD: c_int = 0x0001;
+pub const WDIOS_ENABLECARD: c_int = 0x0002;
+pub const WDIOS_TEMPPANIC: c_int = 0x0004;
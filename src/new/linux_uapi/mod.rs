@@ -1,3 +1,4 @@
 //! This directory maps to `include/uapi` in the Linux source tree.
 
+pub(crate) mod arch;
 pub(crate) mod linux;
@@ -174,26 +174,97 @@ cfg_if! {
 cfg_if! {
     if #[cfg(target_os = "android")] {
         use bionic_libc::kernel_uapi::linux;
+        pub use common::linux_like::link::*;
         pub use linux::types::*;
         pub use sys::socket::*;
     } else if #[cfg(target_os = "linux")] {
+        #[cfg(target_arch = "arm")]
+        pub use arch::arm::hwcap::*;
+        #[cfg(target_arch = "aarch64")]
+        pub use arch::arm64::auxvec::*;
+        #[cfg(target_arch = "aarch64")]
+        pub use arch::arm64::hwcap::*;
+        #[cfg(target_arch = "aarch64")]
+        pub use arch::arm64::sigcontext::*;
+        #[cfg(target_arch = "loongarch64")]
+        pub use arch::loongarch::auxvec::*;
+        #[cfg(target_arch = "loongarch64")]
+        pub use arch::loongarch::hwcap::*;
+        #[cfg(any(
+            target_arch = "mips",
+            target_arch = "mips32r6",
+            target_arch = "mips64",
+            target_arch = "mips64r6"
+        ))]
+        pub use arch::mips::auxvec::*;
+        #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+        pub use arch::powerpc::auxvec::*;
+        #[cfg(any(target_arch = "powerpc", target_arch = "powerpc64"))]
+        pub use arch::powerpc::cputable::*;
+        #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+        pub use arch::riscv::auxvec::*;
+        #[cfg(any(target_arch = "riscv32", target_arch = "riscv64"))]
+        pub use arch::riscv::hwcap::*;
+        #[cfg(target_arch = "riscv64")]
+        pub use arch::riscv::hwprobe::*;
+        #[cfg(target_arch = "s390x")]
+        pub use arch::s390::auxvec::*;
+        #[cfg(any(target_arch = "sparc", target_arch = "sparc64"))]
+        pub use arch::sparc::auxvec::*;
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        pub use arch::x86::auxvec::*;
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        pub use arch::x86::hwcap2::*;
+        #[cfg(all(target_env = "gnu", target_arch = "s390x"))]
+        pub use auxv::*;
+        pub use common::linux_like::bluetooth::hci::*;
+        pub use common::linux_like::bluetooth::l2cap::*;
+        pub use common::linux_like::bluetooth::rfcomm::*;
+        pub use common::linux_like::bluetooth::sco::*;
+        pub use common::linux_like::bluetooth::*;
+        pub use common::linux_like::elf::*;
+        pub use common::linux_like::link::*;
+        pub use common::linux_like::scsi::sg::*;
+        pub use linux::auxvec::*;
+        pub use linux::bsg::*;
         pub use linux::can::bcm::*;
         pub use linux::can::error::*;
+        pub use linux::can::isotp::*;
         pub use linux::can::j1939::*;
         pub use linux::can::netlink::*;
         pub use linux::can::raw::*;
+        pub use linux::cn_proc::*;
+        pub use linux::connector::*;
+        pub use linux::errqueue::*;
+        pub use linux::ethtool::*;
+        pub use linux::ethtool_netlink::*;
         pub use linux::futex::*;
         pub use linux::if_addr::*;
         pub use linux::if_link::*;
         pub use linux::if_packet::*;
+        pub use linux::if_xdp::*;
+        pub use linux::ioprio::*;
         pub use linux::keyctl::*;
         pub use linux::membarrier::*;
         pub use linux::mount::*;
+        pub use linux::mptcp::*;
+        pub use linux::net_tstamp::*;
+        pub use linux::netfilter::nfnetlink::*;
+        pub use linux::netfilter::nfnetlink_conntrack::*;
+        pub use linux::netfilter::nfnetlink_log::*;
+        pub use linux::netfilter::nfnetlink_queue::*;
         pub use linux::netlink::*;
+        pub use linux::nvme_ioctl::*;
         pub use linux::pidfd::*;
+        pub use linux::rseq::*;
+        pub use linux::rtc::*;
         pub use linux::sctp::*;
+        pub use linux::tcp::*;
+        pub use linux::time_types::*;
         pub use linux::tls::*;
         pub use linux::types::*;
+        pub use linux::virtio_net::*;
+        pub use linux::watchdog::*;
         #[cfg(target_env = "gnu")]
         pub use net::route::*;
         #[cfg(target_env = "gnu")]
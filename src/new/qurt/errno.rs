@@ -139,16 +139,18 @@ pub const ENOTRECOVERABLE: c_int = 131;
 pub const ERFKILL: c_int = 132;
 pub const EHWPOISON: c_int = 133;
 
+f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno_location() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno_location() = value }
+    }
+}
+
 extern "C" {
     // Error number access
     #[link_name = "__errno_location"]
     pub fn __errno_location() -> *mut c_int;
 }
-
-pub unsafe fn errno() -> c_int {
-    *__errno_location()
-}
-
-pub unsafe fn set_errno(value: c_int) {
-    *__errno_location() = value;
-}
@@ -1334,6 +1334,10 @@ pub fn errno() -> c_int {
     unsafe { *__errno_location() }
 }
 
+pub fn set_errno(value: c_int) {
+    unsafe { *__errno_location() = value }
+}
+
 pub fn CPU_COUNT_S(size: usize, cpuset: &cpu_set_t) -> c_int {
     let mut s: u32 = 0;
     let size_of_mask = size_of_val(&cpuset.bits[0]);
@@ -2467,6 +2467,14 @@ pub const DEAD_PROCESS: c_short = 8;
 pub const ACCOUNTING: c_short = 9;
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *_Errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *_Errno() = value }
+    }
+
     pub unsafe fn CMSG_FIRSTHDR(mhdr: *const msghdr) -> *mut cmsghdr {
         if (*mhdr).msg_controllen as usize >= size_of::<cmsghdr>() {
             (*mhdr).msg_control as *mut cmsghdr
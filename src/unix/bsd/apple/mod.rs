@@ -4217,6 +4217,14 @@ cfg_if! {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__error() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__error() = value }
+    }
+
     pub unsafe fn CMSG_NXTHDR(mhdr: *const crate::msghdr, cmsg: *const cmsghdr) -> *mut cmsghdr {
         if cmsg.is_null() {
             return crate::CMSG_FIRSTHDR(mhdr);
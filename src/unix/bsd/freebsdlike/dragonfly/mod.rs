@@ -1237,6 +1237,14 @@ const fn _CMSG_ALIGN(n: usize) -> usize {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno_location() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno_location() = value }
+    }
+
     pub unsafe fn CMSG_DATA(cmsg: *const cmsghdr) -> *mut c_uchar {
         (cmsg as *mut c_uchar).offset(_CMSG_ALIGN(size_of::<cmsghdr>()) as isize)
     }
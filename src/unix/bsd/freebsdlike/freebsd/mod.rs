@@ -4319,6 +4319,14 @@ const fn _ALIGN(p: usize) -> usize {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__error() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__error() = value }
+    }
+
     pub unsafe fn CMSG_DATA(cmsg: *const cmsghdr) -> *mut c_uchar {
         (cmsg as *mut c_uchar).add(_ALIGN(size_of::<cmsghdr>()))
     }
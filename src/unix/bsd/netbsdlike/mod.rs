@@ -699,6 +699,16 @@ pub const RB_AUTOBOOT: c_int = 0;
 
 pub const TCP_INFO: c_int = 9;
 
+f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno() = value }
+    }
+}
+
 #[link(name = "util")]
 extern "C" {
     pub fn setgrent();
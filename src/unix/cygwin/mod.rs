@@ -1679,6 +1679,14 @@ pub const FALLOC_FL_INSERT_RANGE: c_int = 0x0010;
 pub const FALLOC_FL_KEEP_SIZE: c_int = 0x1000;
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno() = value }
+    }
+
     pub unsafe fn FD_CLR(fd: c_int, set: *mut fd_set) -> () {
         let fd = fd as usize;
         let size = size_of_val(&(*set).fds_bits[0]) * 8;
@@ -1391,6 +1391,14 @@ const fn CMSG_ALIGN(len: usize) -> usize {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *_errnop() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *_errnop() = value }
+    }
+
     pub unsafe fn CMSG_FIRSTHDR(mhdr: *const msghdr) -> *mut cmsghdr {
         if (*mhdr).msg_controllen as usize >= size_of::<cmsghdr>() {
             (*mhdr).msg_control.cast()
@@ -3518,6 +3518,14 @@ const fn CMSG_ALIGN(len: usize) -> usize {
 
 // functions
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno_location() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno_location() = value }
+    }
+
     pub unsafe fn CMSG_FIRSTHDR(mhdr: *const msghdr) -> *mut cmsghdr {
         if (*mhdr).msg_controllen as usize >= size_of::<cmsghdr>() {
             (*mhdr).msg_control.cast()
@@ -49,11 +49,13 @@ pub type __kernel_pid_t = c_int;
 pub type Elf32_Addr = u32;
 pub type Elf32_Half = u16;
 pub type Elf32_Off = u32;
+pub type Elf32_Sword = i32;
 pub type Elf32_Word = u32;
 
 pub type Elf64_Addr = u64;
 pub type Elf64_Half = u16;
 pub type Elf64_Off = u64;
+pub type Elf64_Sxword = i64;
 pub type Elf64_Word = u32;
 pub type Elf64_Xword = u64;
 
@@ -613,6 +615,28 @@ s! {
 }
 
 s_no_extra_traits! {
+    // linux/elf.h
+
+    pub union __c_anonymous_elf32_dyn_union {
+        pub d_val: Elf32_Sword,
+        pub d_ptr: Elf32_Addr,
+    }
+
+    pub struct Elf32_Dyn {
+        pub d_tag: Elf32_Sword,
+        pub d_un: __c_anonymous_elf32_dyn_union,
+    }
+
+    pub union __c_anonymous_elf64_dyn_union {
+        pub d_val: Elf64_Xword,
+        pub d_ptr: Elf64_Addr,
+    }
+
+    pub struct Elf64_Dyn {
+        pub d_tag: Elf64_Sxword,
+        pub d_un: __c_anonymous_elf64_dyn_union,
+    }
+
     pub struct af_alg_iv {
         pub ivlen: u32,
         pub iv: [c_uchar; 0],
@@ -658,29 +682,6 @@ s_no_extra_traits! {
         pub h_source: [c_uchar; crate::ETH_ALEN as usize],
         pub h_proto: crate::__be16,
     }
-
-    // Internal, for casts to access union fields
-    struct sifields_sigchld {
-        si_pid: crate::pid_t,
-        si_uid: crate::uid_t,
-        si_status: c_int,
-        si_utime: c_long,
-        si_stime: c_long,
-    }
-
-    // Internal, for casts to access union fields
-    union sifields {
-        _align_pointer: *mut c_void,
-        sigchld: sifields_sigchld,
-    }
-
-    // Internal, for casts to access union fields. Note that some variants
-    // of sifields start with a pointer, which makes the alignment of
-    // sifields vary on 32-bit and 64-bit architectures.
-    struct siginfo_f {
-        _siginfo_base: [c_int; 3],
-        sifields: sifields,
-    }
 }
 
 pub const MADV_SOFT_OFFLINE: c_int = 101;
@@ -3422,6 +3423,14 @@ cfg_if! {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno() = value }
+    }
+
     pub unsafe fn CMSG_NXTHDR(mhdr: *const msghdr, cmsg: *const cmsghdr) -> *mut cmsghdr {
         let next = (cmsg as usize + super::CMSG_ALIGN((*cmsg).cmsg_len as usize)) as *mut cmsghdr;
         let max = (*mhdr).msg_control as usize + (*mhdr).msg_controllen as usize;
@@ -3433,9 +3442,16 @@ f! {
     }
 
     pub unsafe fn CPU_ALLOC_SIZE(count: c_int) -> size_t {
-        let _dummy: cpu_set_t = mem::zeroed();
-        let size_in_bits = 8 * size_of_val(&_dummy.__bits[0]);
-        ((count as size_t + size_in_bits - 1) / 8) as size_t
+        let size_in_bits = c_ulong::BITS as usize;
+        (count as size_t + size_in_bits - 1) / size_in_bits * size_of::<c_ulong>()
+    }
+
+    pub unsafe fn CPU_ALLOC(count: c_int) -> *mut cpu_set_t {
+        crate::calloc(1, CPU_ALLOC_SIZE(count)).cast()
+    }
+
+    pub unsafe fn CPU_FREE(cpuset: *mut cpu_set_t) -> () {
+        crate::free(cpuset.cast());
     }
 
     pub unsafe fn CPU_ZERO(cpuset: &mut cpu_set_t) -> () {
@@ -3460,13 +3476,34 @@ f! {
         0 != (cpuset.__bits[idx] & (1 << offset))
     }
 
-    pub unsafe fn CPU_COUNT_S(size: usize, cpuset: &cpu_set_t) -> c_int {
-        let mut s: u32 = 0;
-        let size_of_mask = size_of_val(&cpuset.__bits[0]);
-        for i in &cpuset.__bits[..(size / size_of_mask)] {
-            s += i.count_ones();
+    pub unsafe fn CPU_ZERO_S(size: usize, cpuset: *mut cpu_set_t) -> () {
+        ptr::write_bytes(cpuset.cast::<u8>(), 0, size);
+    }
+
+    pub unsafe fn CPU_SET_S(cpu: usize, size: usize, cpuset: *mut cpu_set_t) -> () {
+        let size_in_bits = c_ulong::BITS as usize;
+        if cpu / 8 < size {
+            let (idx, offset) = (cpu / size_in_bits, cpu % size_in_bits);
+            *cpuset.cast::<c_ulong>().add(idx) |= 1 << offset;
+        }
+    }
+
+    pub unsafe fn CPU_CLR_S(cpu: usize, size: usize, cpuset: *mut cpu_set_t) -> () {
+        let size_in_bits = c_ulong::BITS as usize;
+        if cpu / 8 < size {
+            let (idx, offset) = (cpu / size_in_bits, cpu % size_in_bits);
+            *cpuset.cast::<c_ulong>().add(idx) &= !(1 << offset);
         }
-        s as c_int
+    }
+
+    pub unsafe fn CPU_ISSET_S(cpu: usize, size: usize, cpuset: *const cpu_set_t) -> bool {
+        let size_in_bits = c_ulong::BITS as usize;
+        let (idx, offset) = (cpu / size_in_bits, cpu % size_in_bits);
+        cpu / 8 < size && 0 != (*cpuset.cast::<c_ulong>().add(idx) & (1 << offset))
+    }
+
+    pub unsafe fn CPU_COUNT_S(size: usize, cpuset: *const cpu_set_t) -> c_int {
+        __sched_cpucount(size, cpuset)
     }
 
     pub unsafe fn CPU_COUNT(cpuset: &cpu_set_t) -> c_int {
@@ -3477,6 +3514,72 @@ f! {
         set1.__bits == set2.__bits
     }
 
+    pub unsafe fn CPU_EQUAL_S(size: usize, set1: *const cpu_set_t, set2: *const cpu_set_t) -> bool {
+        let set1 = core::slice::from_raw_parts(set1.cast::<u8>(), size);
+        let set2 = core::slice::from_raw_parts(set2.cast::<u8>(), size);
+        set1 == set2
+    }
+
+    pub unsafe fn CPU_AND_S(
+        size: usize,
+        destset: *mut cpu_set_t,
+        srcset1: *const cpu_set_t,
+        srcset2: *const cpu_set_t,
+    ) -> () {
+        let dest = destset.cast::<c_ulong>();
+        let (src1, src2) = (srcset1.cast::<c_ulong>(), srcset2.cast::<c_ulong>());
+        for i in 0..size / size_of::<c_ulong>() {
+            *dest.add(i) = *src1.add(i) & *src2.add(i);
+        }
+    }
+
+    pub unsafe fn CPU_AND(destset: &mut cpu_set_t, srcset1: &cpu_set_t, srcset2: &cpu_set_t) -> () {
+        let srcs = srcset1.__bits.iter().zip(&srcset2.__bits);
+        for (dest, (src1, src2)) in destset.__bits.iter_mut().zip(srcs) {
+            *dest = src1 & src2;
+        }
+    }
+
+    pub unsafe fn CPU_OR_S(
+        size: usize,
+        destset: *mut cpu_set_t,
+        srcset1: *const cpu_set_t,
+        srcset2: *const cpu_set_t,
+    ) -> () {
+        let dest = destset.cast::<c_ulong>();
+        let (src1, src2) = (srcset1.cast::<c_ulong>(), srcset2.cast::<c_ulong>());
+        for i in 0..size / size_of::<c_ulong>() {
+            *dest.add(i) = *src1.add(i) | *src2.add(i);
+        }
+    }
+
+    pub unsafe fn CPU_OR(destset: &mut cpu_set_t, srcset1: &cpu_set_t, srcset2: &cpu_set_t) -> () {
+        let srcs = srcset1.__bits.iter().zip(&srcset2.__bits);
+        for (dest, (src1, src2)) in destset.__bits.iter_mut().zip(srcs) {
+            *dest = src1 | src2;
+        }
+    }
+
+    pub unsafe fn CPU_XOR_S(
+        size: usize,
+        destset: *mut cpu_set_t,
+        srcset1: *const cpu_set_t,
+        srcset2: *const cpu_set_t,
+    ) -> () {
+        let dest = destset.cast::<c_ulong>();
+        let (src1, src2) = (srcset1.cast::<c_ulong>(), srcset2.cast::<c_ulong>());
+        for i in 0..size / size_of::<c_ulong>() {
+            *dest.add(i) = *src1.add(i) ^ *src2.add(i);
+        }
+    }
+
+    pub unsafe fn CPU_XOR(destset: &mut cpu_set_t, srcset1: &cpu_set_t, srcset2: &cpu_set_t) -> () {
+        let srcs = srcset1.__bits.iter().zip(&srcset2.__bits);
+        for (dest, (src1, src2)) in destset.__bits.iter_mut().zip(srcs) {
+            *dest = src1 ^ src2;
+        }
+    }
+
     pub unsafe fn NLA_ALIGN(len: c_int) -> c_int {
         return ((len) + NLA_ALIGNTO - 1) & !(NLA_ALIGNTO - 1);
     }
@@ -3913,55 +4016,3 @@ cfg_if! {
         // Unknown target_pointer_width
     }
 }
-
-impl siginfo_t {
-    pub unsafe fn si_addr(&self) -> *mut c_void {
-        #[repr(C)]
-        struct siginfo_sigfault {
-            _si_signo: c_int,
-            _si_errno: c_int,
-            _si_code: c_int,
-            si_addr: *mut c_void,
-        }
-        (*(self as *const siginfo_t).cast::<siginfo_sigfault>()).si_addr
-    }
-
-    pub unsafe fn si_value(&self) -> crate::sigval {
-        #[repr(C)]
-        struct siginfo_timer {
-            _si_signo: c_int,
-            _si_errno: c_int,
-            _si_code: c_int,
-            _si_tid: c_int,
-            _si_overrun: c_int,
-            si_sigval: crate::sigval,
-        }
-        (*(self as *const siginfo_t).cast::<siginfo_timer>()).si_sigval
-    }
-}
-
-impl siginfo_t {
-    unsafe fn sifields(&self) -> &sifields {
-        &(*(self as *const siginfo_t).cast::<siginfo_f>()).sifields
-    }
-
-    pub unsafe fn si_pid(&self) -> crate::pid_t {
-        self.sifields().sigchld.si_pid
-    }
-
-    pub unsafe fn si_uid(&self) -> crate::uid_t {
-        self.sifields().sigchld.si_uid
-    }
-
-    pub unsafe fn si_status(&self) -> c_int {
-        self.sifields().sigchld.si_status
-    }
-
-    pub unsafe fn si_utime(&self) -> c_long {
-        self.sifields().sigchld.si_utime
-    }
-
-    pub unsafe fn si_stime(&self) -> c_long {
-        self.sifields().sigchld.si_stime
-    }
-}
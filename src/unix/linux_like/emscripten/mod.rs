@@ -1280,6 +1280,14 @@ pub const PRIO_USER: c_int = 2;
 pub const SOMAXCONN: c_int = 128;
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno_location() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno_location() = value }
+    }
+
     pub unsafe fn CMSG_NXTHDR(mhdr: *const msghdr, cmsg: *const cmsghdr) -> *mut cmsghdr {
         if ((*cmsg).cmsg_len as usize) < size_of::<cmsghdr>() {
             return ptr::null_mut();
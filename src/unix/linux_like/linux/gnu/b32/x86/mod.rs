@@ -7,7 +7,49 @@ use crate::{
 pub type wchar_t = i32;
 pub type greg_t = i32;
 
+// link.h
+pub type la_i86_gnu_pltenter = unsafe extern "C" fn(
+    sym: *mut crate::Elf32_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    regs: *mut La_i86_regs,
+    flags: *mut c_uint,
+    symname: *const c_char,
+    framesizep: *mut c_long,
+) -> crate::Elf32_Addr;
+pub type la_i86_gnu_pltexit = unsafe extern "C" fn(
+    sym: *mut crate::Elf32_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    inregs: *const La_i86_regs,
+    outregs: *mut La_i86_retval,
+    symname: *const c_char,
+) -> c_uint;
+
 s! {
+    // link.h
+
+    pub struct La_i86_regs {
+        pub lr_edx: u32,
+        pub lr_ecx: u32,
+        pub lr_eax: u32,
+        pub lr_ebp: u32,
+        pub lr_esp: u32,
+    }
+
+    pub struct La_i86_retval {
+        pub lrv_eax: u32,
+        pub lrv_edx: u32,
+        /// x87 `long double`.
+        pub lrv_st0: [u32; 3],
+        /// x87 `long double`.
+        pub lrv_st1: [u32; 3],
+        __glibc_unused1: Padding<u64>,
+        __glibc_unused2: Padding<u64>,
+    }
+
     pub struct statfs {
         pub f_type: crate::__fsword_t,
         pub f_bsize: crate::__fsword_t,
@@ -11,6 +11,27 @@ pub type nlink_t = u32;
 pub type blksize_t = i32;
 pub type suseconds_t = i64;
 
+// link.h
+pub type la_aarch64_gnu_pltenter = unsafe extern "C" fn(
+    sym: *mut crate::Elf64_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    regs: *mut La_aarch64_regs,
+    flags: *mut c_uint,
+    symname: *const c_char,
+    framesizep: *mut c_long,
+) -> crate::Elf64_Addr;
+pub type la_aarch64_gnu_pltexit = unsafe extern "C" fn(
+    sym: *mut crate::Elf64_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    inregs: *const La_aarch64_regs,
+    outregs: *mut La_aarch64_retval,
+    symname: *const c_char,
+) -> c_uint;
+
 s! {
     pub struct statfs {
         pub f_type: crate::__fsword_t,
@@ -236,6 +257,29 @@ s_no_extra_traits! {
     pub struct max_align_t {
         priv_: [f32; 8],
     }
+
+    // link.h, layout since glibc 2.35 (`LAV_CURRENT` 2)
+
+    pub union La_aarch64_vector {
+        pub s: f32,
+        pub d: f64,
+        /// IEEE quadruple precision `long double`.
+        pub q: u128,
+    }
+
+    pub struct La_aarch64_regs {
+        pub lr_xreg: [u64; 9],
+        pub lr_vreg: [La_aarch64_vector; 8],
+        pub lr_sp: u64,
+        pub lr_lr: u64,
+        pub lr_vpcs: *mut c_void,
+    }
+
+    pub struct La_aarch64_retval {
+        pub lrv_xreg: [u64; 8],
+        pub lrv_vreg: [La_aarch64_vector; 8],
+        pub lrv_vpcs: *mut c_void,
+    }
 }
 
 pub const VEOF: usize = 4;
@@ -524,52 +568,6 @@ pub const TCSANOW: c_int = 0;
 pub const TCSADRAIN: c_int = 1;
 pub const TCSAFLUSH: c_int = 2;
 
-// sys/auxv.h
-pub const HWCAP_FP: c_ulong = 1 << 0;
-pub const HWCAP_ASIMD: c_ulong = 1 << 1;
-pub const HWCAP_EVTSTRM: c_ulong = 1 << 2;
-pub const HWCAP_AES: c_ulong = 1 << 3;
-pub const HWCAP_PMULL: c_ulong = 1 << 4;
-pub const HWCAP_SHA1: c_ulong = 1 << 5;
-pub const HWCAP_SHA2: c_ulong = 1 << 6;
-pub const HWCAP_CRC32: c_ulong = 1 << 7;
-pub const HWCAP_ATOMICS: c_ulong = 1 << 8;
-pub const HWCAP_FPHP: c_ulong = 1 << 9;
-pub const HWCAP_ASIMDHP: c_ulong = 1 << 10;
-pub const HWCAP_CPUID: c_ulong = 1 << 11;
-pub const HWCAP_ASIMDRDM: c_ulong = 1 << 12;
-pub const HWCAP_JSCVT: c_ulong = 1 << 13;
-pub const HWCAP_FCMA: c_ulong = 1 << 14;
-pub const HWCAP_LRCPC: c_ulong = 1 << 15;
-pub const HWCAP_DCPOP: c_ulong = 1 << 16;
-pub const HWCAP_SHA3: c_ulong = 1 << 17;
-pub const HWCAP_SM3: c_ulong = 1 << 18;
-pub const HWCAP_SM4: c_ulong = 1 << 19;
-pub const HWCAP_ASIMDDP: c_ulong = 1 << 20;
-pub const HWCAP_SHA512: c_ulong = 1 << 21;
-pub const HWCAP_SVE: c_ulong = 1 << 22;
-pub const HWCAP_ASIMDFHM: c_ulong = 1 << 23;
-pub const HWCAP_DIT: c_ulong = 1 << 24;
-pub const HWCAP_USCAT: c_ulong = 1 << 25;
-pub const HWCAP_ILRCPC: c_ulong = 1 << 26;
-pub const HWCAP_FLAGM: c_ulong = 1 << 27;
-pub const HWCAP_SSBS: c_ulong = 1 << 28;
-pub const HWCAP_SB: c_ulong = 1 << 29;
-pub const HWCAP_PACA: c_ulong = 1 << 30;
-pub const HWCAP_PACG: c_ulong = 1 << 31;
-// FIXME(linux): enable these again once linux-api-headers are up to date enough on CI.
-// See discussion in https://github.com/rust-lang/libc/pull/1638
-//pub const HWCAP2_DCPODP: c_ulong = 1 << 0;
-//pub const HWCAP2_SVE2: c_ulong = 1 << 1;
-//pub const HWCAP2_SVEAES: c_ulong = 1 << 2;
-//pub const HWCAP2_SVEPMULL: c_ulong = 1 << 3;
-//pub const HWCAP2_SVEBITPERM: c_ulong = 1 << 4;
-//pub const HWCAP2_SVESHA3: c_ulong = 1 << 5;
-//pub const HWCAP2_SVESM4: c_ulong = 1 << 6;
-//pub const HWCAP2_FLAGM2: c_ulong = 1 << 7;
-//pub const HWCAP2_FRINT: c_ulong = 1 << 8;
-//pub const HWCAP2_MTE: c_ulong = 1 << 18;
-
 // linux/prctl.h
 pub const PR_PAC_RESET_KEYS: c_int = 54;
 pub const PR_SET_TAGGED_ADDR_CTRL: c_int = 55;
@@ -14,7 +14,46 @@ pub type fsblkcnt64_t = c_ulong;
 pub type fsfilcnt64_t = c_ulong;
 pub type suseconds_t = i64;
 
+// link.h
+pub type la_riscv_gnu_pltenter = unsafe extern "C" fn(
+    sym: *mut crate::Elf64_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    regs: *mut La_riscv_regs,
+    flags: *mut c_uint,
+    symname: *const c_char,
+    framesizep: *mut c_long,
+) -> crate::Elf64_Addr;
+pub type la_riscv_gnu_pltexit = unsafe extern "C" fn(
+    sym: *mut crate::Elf64_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    inregs: *const La_riscv_regs,
+    outregs: *mut La_riscv_retval,
+    symname: *const c_char,
+) -> c_uint;
+
 s! {
+    // link.h
+
+    pub struct La_riscv_regs {
+        /// `a0` to `a7`
+        pub lr_reg: [c_ulong; 8],
+        /// `fa0` to `fa7`
+        pub lr_fpreg: [f64; 8],
+        pub lr_ra: c_ulong,
+        pub lr_sp: c_ulong,
+    }
+
+    pub struct La_riscv_retval {
+        pub lrv_a0: c_ulong,
+        pub lrv_a1: c_ulong,
+        pub lrv_fa0: f64,
+        pub lrv_fa1: f64,
+    }
+
     pub struct pthread_attr_t {
         __size: [c_ulong; 7],
     }
@@ -563,15 +602,6 @@ pub const REG_A0: usize = 10;
 pub const REG_S2: usize = 18;
 pub const REG_NARGS: usize = 8;
 
-pub const COMPAT_HWCAP_ISA_I: c_ulong = 1 << (b'I' - b'A');
-pub const COMPAT_HWCAP_ISA_M: c_ulong = 1 << (b'M' - b'A');
-#[allow(clippy::eq_op)]
-pub const COMPAT_HWCAP_ISA_A: c_ulong = 1 << (b'A' - b'A');
-pub const COMPAT_HWCAP_ISA_F: c_ulong = 1 << (b'F' - b'A');
-pub const COMPAT_HWCAP_ISA_D: c_ulong = 1 << (b'D' - b'A');
-pub const COMPAT_HWCAP_ISA_C: c_ulong = 1 << (b'C' - b'A');
-pub const COMPAT_HWCAP_ISA_V: c_ulong = 1 << (b'V' - b'A');
-
 pub const SYS_read: c_long = 63;
 pub const SYS_write: c_long = 64;
 pub const SYS_close: c_long = 57;
@@ -621,6 +651,7 @@ pub const SYS_getsockopt: c_long = 209;
 pub const SYS_clone: c_long = 220;
 pub const SYS_execve: c_long = 221;
 pub const SYS_exit: c_long = 93;
+pub const SYS_riscv_hwprobe: c_long = 258;
 pub const SYS_wait4: c_long = 260;
 pub const SYS_kill: c_long = 129;
 pub const SYS_uname: c_long = 160;
@@ -875,3 +906,14 @@ pub const SYS_memfd_secret: c_long = 447;
 pub const SYS_process_mrelease: c_long = 448;
 pub const SYS_futex_waitv: c_long = 449;
 pub const SYS_set_mempolicy_home_node: c_long = 450;
+
+extern "C" {
+    // Added in glibc 2.40
+    pub fn __riscv_hwprobe(
+        pairs: *mut crate::riscv_hwprobe,
+        pair_count: size_t,
+        cpusetsize: size_t,
+        cpus: *mut crate::cpu_set_t,
+        flags: c_uint,
+    ) -> c_int;
+}
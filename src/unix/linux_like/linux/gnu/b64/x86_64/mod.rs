@@ -12,6 +12,52 @@ pub type blksize_t = i64;
 pub type greg_t = i64;
 pub type suseconds_t = i64;
 
+// link.h
+pub type La_x86_64_xmm = [f32; 4];
+pub type La_x86_64_ymm = [f32; 8];
+pub type La_x86_64_zmm = [f64; 8];
+pub type La_x32_regs = La_x86_64_regs;
+pub type La_x32_retval = La_x86_64_retval;
+
+pub type la_x86_64_gnu_pltenter = unsafe extern "C" fn(
+    sym: *mut crate::Elf64_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    regs: *mut La_x86_64_regs,
+    flags: *mut c_uint,
+    symname: *const c_char,
+    framesizep: *mut c_long,
+) -> crate::Elf64_Addr;
+pub type la_x86_64_gnu_pltexit = unsafe extern "C" fn(
+    sym: *mut crate::Elf64_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    inregs: *const La_x86_64_regs,
+    outregs: *mut La_x86_64_retval,
+    symname: *const c_char,
+) -> c_uint;
+pub type la_x32_gnu_pltenter = unsafe extern "C" fn(
+    sym: *mut crate::Elf32_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    regs: *mut La_x32_regs,
+    flags: *mut c_uint,
+    symname: *const c_char,
+    framesizep: *mut c_long,
+) -> crate::Elf32_Addr;
+pub type la_x32_gnu_pltexit = unsafe extern "C" fn(
+    sym: *mut crate::Elf32_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    inregs: *const La_x32_regs,
+    outregs: *mut La_x32_retval,
+    symname: *const c_char,
+) -> c_uint;
+
 s! {
     pub struct statfs {
         pub f_type: crate::__fsword_t,
@@ -307,6 +353,47 @@ s_no_extra_traits! {
     pub struct max_align_t {
         priv_: [f64; 4],
     }
+
+    #[repr(align(16))]
+    pub union La_x86_64_vector {
+        pub ymm: [La_x86_64_ymm; 2],
+        pub zmm: [La_x86_64_zmm; 1],
+        pub xmm: [La_x86_64_xmm; 4],
+    }
+
+    #[repr(align(16))]
+    pub struct La_x86_64_regs {
+        pub lr_rdx: u64,
+        pub lr_r8: u64,
+        pub lr_r9: u64,
+        pub lr_rcx: u64,
+        pub lr_rsi: u64,
+        pub lr_rdi: u64,
+        pub lr_rbp: u64,
+        pub lr_rsp: u64,
+        pub lr_xmm: [La_x86_64_xmm; 8],
+        pub lr_vector: [La_x86_64_vector; 8],
+        #[cfg(target_pointer_width = "64")]
+        __glibc_unused1: Padding<[u128; 4]>,
+    }
+
+    #[repr(align(16))]
+    pub struct La_x86_64_retval {
+        pub lrv_rax: u64,
+        pub lrv_rdx: u64,
+        pub lrv_xmm0: La_x86_64_xmm,
+        pub lrv_xmm1: La_x86_64_xmm,
+        /// x87 `long double`, padded to 16 bytes.
+        pub lrv_st0: [u64; 2],
+        /// x87 `long double`, padded to 16 bytes.
+        pub lrv_st1: [u64; 2],
+        pub lrv_vector0: La_x86_64_vector,
+        pub lrv_vector1: La_x86_64_vector,
+        #[cfg(target_pointer_width = "64")]
+        __glibc_unused1: Padding<u128>,
+        #[cfg(target_pointer_width = "64")]
+        __glibc_unused2: Padding<u128>,
+    }
 }
 
 pub const POSIX_FADV_DONTNEED: c_int = 4;
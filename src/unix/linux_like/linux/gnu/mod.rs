@@ -7,7 +7,39 @@ pub type __rlimit_resource_t = c_uint;
 pub type Lmid_t = c_long;
 pub type regoff_t = c_int;
 pub type __kernel_rwf_t = c_int;
-pub type __be16 = crate::__u16;
+
+// Signatures of the `LD_AUDIT` interface in <link.h>. These functions are not provided by glibc;
+// an audit library exports them under these names with `#[no_mangle]`.
+pub type la_version = unsafe extern "C" fn(version: c_uint) -> c_uint;
+pub type la_activity = unsafe extern "C" fn(cookie: *mut crate::uintptr_t, flag: c_uint);
+pub type la_objsearch = unsafe extern "C" fn(
+    name: *const c_char,
+    cookie: *mut crate::uintptr_t,
+    flag: c_uint,
+) -> *mut c_char;
+pub type la_objopen = unsafe extern "C" fn(
+    map: *mut crate::link_map,
+    lmid: Lmid_t,
+    cookie: *mut crate::uintptr_t,
+) -> c_uint;
+pub type la_preinit = unsafe extern "C" fn(cookie: *mut crate::uintptr_t);
+pub type la_symbind32 = unsafe extern "C" fn(
+    sym: *mut crate::Elf32_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    flags: *mut c_uint,
+    symname: *const c_char,
+) -> crate::uintptr_t;
+pub type la_symbind64 = unsafe extern "C" fn(
+    sym: *mut crate::Elf64_Sym,
+    ndx: c_uint,
+    refcook: *mut crate::uintptr_t,
+    defcook: *mut crate::uintptr_t,
+    flags: *mut c_uint,
+    symname: *const c_char,
+) -> crate::uintptr_t;
+pub type la_objclose = unsafe extern "C" fn(cookie: *mut crate::uintptr_t) -> c_uint;
 
 cfg_if! {
     if #[cfg(doc)] {
@@ -134,19 +166,6 @@ s! {
         __bitfield: u8,
     }
 
-    pub struct Elf64_Chdr {
-        pub ch_type: crate::Elf64_Word,
-        pub ch_reserved: crate::Elf64_Word,
-        pub ch_size: crate::Elf64_Xword,
-        pub ch_addralign: crate::Elf64_Xword,
-    }
-
-    pub struct Elf32_Chdr {
-        pub ch_type: crate::Elf32_Word,
-        pub ch_size: crate::Elf32_Word,
-        pub ch_addralign: crate::Elf32_Word,
-    }
-
     pub struct seminfo {
         pub semmap: c_int,
         pub semmni: c_int,
@@ -406,31 +425,45 @@ s! {
         pub ut_addr_v6: [i32; 4],
         __glibc_reserved: Padding<[c_char; 20]>,
     }
-}
 
-impl siginfo_t {
-    pub unsafe fn si_addr(&self) -> *mut c_void {
-        #[repr(C)]
-        struct siginfo_sigfault {
-            _si_signo: c_int,
-            _si_errno: c_int,
-            _si_code: c_int,
-            si_addr: *mut c_void,
-        }
-        (*(self as *const siginfo_t).cast::<siginfo_sigfault>()).si_addr
+    // dlfcn.h
+
+    pub struct Dl_serpath {
+        pub dls_name: *mut c_char,
+        pub dls_flags: c_uint,
     }
 
-    pub unsafe fn si_value(&self) -> crate::sigval {
-        #[repr(C)]
-        struct siginfo_timer {
-            _si_signo: c_int,
-            _si_errno: c_int,
-            _si_code: c_int,
-            _si_tid: c_int,
-            _si_overrun: c_int,
-            si_sigval: crate::sigval,
-        }
-        (*(self as *const siginfo_t).cast::<siginfo_timer>()).si_sigval
+    /// Argument of the [`RTLD_DI_SERINFO`] and [`RTLD_DI_SERINFOSIZE`] requests.
+    pub struct Dl_serinfo {
+        pub dls_size: size_t,
+        pub dls_cnt: c_uint,
+        /// Actually longer, `dls_cnt` elements.
+        pub dls_serpath: [Dl_serpath; 1],
+    }
+
+    pub struct dl_find_object {
+        pub dlfo_flags: c_ulonglong,
+        pub dlfo_map_start: *mut c_void,
+        pub dlfo_map_end: *mut c_void,
+        pub dlfo_link_map: *mut crate::link_map,
+        pub dlfo_eh_frame: *mut c_void,
+        #[cfg(target_arch = "x86")]
+        pub dlfo_eh_dbase: *mut c_void,
+        #[cfg(target_arch = "x86")]
+        __dlfo_eh_dbase_pad: Padding<c_uint>,
+        #[cfg(target_arch = "arm")]
+        pub dlfo_eh_count: c_int,
+        #[cfg(target_arch = "arm")]
+        __dlfo_eh_count_pad: Padding<c_uint>,
+        __dlfo_reserved: Padding<[c_ulonglong; 7]>,
+    }
+
+    // link.h
+
+    pub struct r_debug_extended {
+        pub base: crate::r_debug,
+        /// Added in `r_version` 2; the next namespace's rendezvous structure.
+        pub r_next: *mut r_debug_extended,
     }
 }
 
@@ -466,55 +499,6 @@ s_no_extra_traits! {
         pub h_source: [c_uchar; crate::ETH_ALEN as usize],
         pub h_proto: crate::__be16,
     }
-
-    // Internal, for casts to access union fields
-    struct sifields_sigchld {
-        si_pid: crate::pid_t,
-        si_uid: crate::uid_t,
-        si_status: c_int,
-        si_utime: c_long,
-        si_stime: c_long,
-    }
-
-    // Internal, for casts to access union fields
-    union sifields {
-        _align_pointer: *mut c_void,
-        sigchld: sifields_sigchld,
-    }
-
-    // Internal, for casts to access union fields. Note that some variants
-    // of sifields start with a pointer, which makes the alignment of
-    // sifields vary on 32-bit and 64-bit architectures.
-    struct siginfo_f {
-        _siginfo_base: [c_int; 3],
-        sifields: sifields,
-    }
-}
-
-impl siginfo_t {
-    unsafe fn sifields(&self) -> &sifields {
-        &(*(self as *const siginfo_t).cast::<siginfo_f>()).sifields
-    }
-
-    pub unsafe fn si_pid(&self) -> crate::pid_t {
-        self.sifields().sigchld.si_pid
-    }
-
-    pub unsafe fn si_uid(&self) -> crate::uid_t {
-        self.sifields().sigchld.si_uid
-    }
-
-    pub unsafe fn si_status(&self) -> c_int {
-        self.sifields().sigchld.si_status
-    }
-
-    pub unsafe fn si_utime(&self) -> c_long {
-        self.sifields().sigchld.si_utime
-    }
-
-    pub unsafe fn si_stime(&self) -> c_long {
-        self.sifields().sigchld.si_stime
-    }
 }
 
 s_no_extra_traits! {
@@ -610,6 +594,10 @@ pub const USER_PROCESS: c_short = 7;
 pub const DEAD_PROCESS: c_short = 8;
 pub const ACCOUNTING: c_short = 9;
 
+// spawn.h
+// Added in `glibc` 2.39
+pub const POSIX_SPAWN_SETCGROUP: c_short = 0x100;
+
 // dlfcn.h
 pub const LM_ID_BASE: c_long = 0;
 pub const LM_ID_NEWLM: c_long = -1;
@@ -624,6 +612,55 @@ pub const RTLD_DI_PROFILENAME: c_int = 7;
 pub const RTLD_DI_PROFILEOUT: c_int = 8;
 pub const RTLD_DI_TLS_MODID: c_int = 9;
 pub const RTLD_DI_TLS_DATA: c_int = 10;
+pub const RTLD_DI_PHDR: c_int = 11;
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const RTLD_DI_MAX: c_int = 11;
+
+#[cfg(target_arch = "arm")]
+pub const DLFO_EH_SEGMENT_TYPE: u32 = 0x70000001; // PT_ARM_EXIDX
+#[cfg(not(target_arch = "arm"))]
+pub const DLFO_EH_SEGMENT_TYPE: u32 = crate::PT_GNU_EH_FRAME;
+
+// link.h
+
+/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
+/// for details.
+pub const LAV_CURRENT: c_uint = 2;
+
+c_enum! {
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub LA_ACT_CONSISTENT = 0,
+        pub LA_ACT_ADD,
+        pub LA_ACT_DELETE,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub LA_SER_ORIG = 0x01,
+        pub LA_SER_LIBPATH = 0x02,
+        pub LA_SER_RUNPATH = 0x04,
+        pub LA_SER_CONFIG = 0x08,
+        pub LA_SER_DEFAULT = 0x40,
+        pub LA_SER_SECURE = 0x80,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub LA_FLG_BINDTO = 0x01,
+        pub LA_FLG_BINDFROM = 0x02,
+    }
+
+    #[repr(c_uint)]
+    pub enum #anon {
+        pub LA_SYMB_NOPLTENTER = 0x01,
+        pub LA_SYMB_NOPLTEXIT = 0x02,
+        pub LA_SYMB_STRUCTCALL = 0x04,
+        pub LA_SYMB_DLSYM = 0x08,
+        pub LA_SYMB_ALTVALUE = 0x10,
+    }
+}
 
 pub const SOCK_NONBLOCK: c_int = O_NONBLOCK;
 
@@ -1268,6 +1305,41 @@ extern "C" {
         actions: *mut crate::posix_spawn_file_actions_t,
         tcfd: c_int,
     ) -> c_int;
+    // Added in `glibc` 2.42 under the names standardized by POSIX.1-2024
+    pub fn posix_spawn_file_actions_addchdir(
+        actions: *mut crate::posix_spawn_file_actions_t,
+        path: *const c_char,
+    ) -> c_int;
+    pub fn posix_spawn_file_actions_addfchdir(
+        actions: *mut crate::posix_spawn_file_actions_t,
+        fd: c_int,
+    ) -> c_int;
+    // Added in `glibc` 2.39
+    pub fn posix_spawnattr_getcgroup_np(
+        attr: *const crate::posix_spawnattr_t,
+        cgroup: *mut c_int,
+    ) -> c_int;
+    pub fn posix_spawnattr_setcgroup_np(
+        attr: *mut crate::posix_spawnattr_t,
+        cgroup: c_int,
+    ) -> c_int;
+    // Added in `glibc` 2.39
+    pub fn pidfd_spawn(
+        pidfd: *mut c_int,
+        path: *const c_char,
+        file_actions: *const crate::posix_spawn_file_actions_t,
+        attrp: *const crate::posix_spawnattr_t,
+        argv: *const *mut c_char,
+        envp: *const *mut c_char,
+    ) -> c_int;
+    pub fn pidfd_spawnp(
+        pidfd: *mut c_int,
+        file: *const c_char,
+        file_actions: *const crate::posix_spawn_file_actions_t,
+        attrp: *const crate::posix_spawnattr_t,
+        argv: *const *mut c_char,
+        envp: *const *mut c_char,
+    ) -> c_int;
 
     // mntent.h
     pub fn getmntent_r(
@@ -1303,8 +1375,23 @@ extern "C" {
 
     pub fn tgkill(tgid: crate::pid_t, tid: crate::pid_t, sig: c_int) -> c_int;
 
+    pub fn __sched_cpualloc(count: size_t) -> *mut crate::cpu_set_t;
+    pub fn __sched_cpufree(set: *mut crate::cpu_set_t);
+    pub fn __sched_cpucount(setsize: size_t, setp: *const crate::cpu_set_t) -> c_int;
+
     // glibc provides this in <unistd.h> with _GNU_SOURCE
     pub static mut environ: *mut *mut c_char;
+
+    // Added in glibc 2.35, <sys/rseq.h>
+    pub static __rseq_offset: crate::ptrdiff_t;
+    pub static __rseq_size: c_uint;
+    pub static __rseq_flags: c_uint;
+
+    // <link.h>
+    pub static mut _r_debug: crate::r_debug;
+
+    // Added in glibc 2.35, <dlfcn.h>
+    pub fn _dl_find_object(address: *mut c_void, result: *mut dl_find_object) -> c_int;
 }
 
 cfg_if! {
@@ -32,6 +32,7 @@ pub type pthread_once_t = c_int;
 pub type pthread_spinlock_t = c_int;
 pub type __kernel_fsid_t = __c_anonymous__kernel_fsid_t;
 pub type __kernel_clockid_t = c_int;
+pub type __kernel_time64_t = c_longlong;
 
 pub type eventfd_t = u64;
 
@@ -298,16 +299,6 @@ s! {
         pub svm_zero: [u8; 3],
     }
 
-    pub struct sock_extended_err {
-        pub ee_errno: u32,
-        pub ee_origin: u8,
-        pub ee_type: u8,
-        pub ee_code: u8,
-        pub ee_pad: u8,
-        pub ee_info: u32,
-        pub ee_data: u32,
-    }
-
     // linux/seccomp.h
     pub struct seccomp_data {
         pub nr: c_int,
@@ -650,93 +641,6 @@ s! {
         pub rsv: [c_int; 11],
     }
 
-    // linux/if_xdp.h
-
-    pub struct sockaddr_xdp {
-        pub sxdp_family: crate::__u16,
-        pub sxdp_flags: crate::__u16,
-        pub sxdp_ifindex: crate::__u32,
-        pub sxdp_queue_id: crate::__u32,
-        pub sxdp_shared_umem_fd: crate::__u32,
-    }
-
-    pub struct xdp_ring_offset {
-        pub producer: crate::__u64,
-        pub consumer: crate::__u64,
-        pub desc: crate::__u64,
-        pub flags: crate::__u64,
-    }
-
-    pub struct xdp_mmap_offsets {
-        pub rx: xdp_ring_offset,
-        pub tx: xdp_ring_offset,
-        pub fr: xdp_ring_offset,
-        pub cr: xdp_ring_offset,
-    }
-
-    pub struct xdp_ring_offset_v1 {
-        pub producer: crate::__u64,
-        pub consumer: crate::__u64,
-        pub desc: crate::__u64,
-    }
-
-    pub struct xdp_mmap_offsets_v1 {
-        pub rx: xdp_ring_offset_v1,
-        pub tx: xdp_ring_offset_v1,
-        pub fr: xdp_ring_offset_v1,
-        pub cr: xdp_ring_offset_v1,
-    }
-
-    pub struct xdp_umem_reg {
-        pub addr: crate::__u64,
-        pub len: crate::__u64,
-        pub chunk_size: crate::__u32,
-        pub headroom: crate::__u32,
-        pub flags: crate::__u32,
-        pub tx_metadata_len: crate::__u32,
-    }
-
-    pub struct xdp_umem_reg_v1 {
-        pub addr: crate::__u64,
-        pub len: crate::__u64,
-        pub chunk_size: crate::__u32,
-        pub headroom: crate::__u32,
-    }
-
-    pub struct xdp_statistics {
-        pub rx_dropped: crate::__u64,
-        pub rx_invalid_descs: crate::__u64,
-        pub tx_invalid_descs: crate::__u64,
-        pub rx_ring_full: crate::__u64,
-        pub rx_fill_ring_empty_descs: crate::__u64,
-        pub tx_ring_empty_descs: crate::__u64,
-    }
-
-    pub struct xdp_statistics_v1 {
-        pub rx_dropped: crate::__u64,
-        pub rx_invalid_descs: crate::__u64,
-        pub tx_invalid_descs: crate::__u64,
-    }
-
-    pub struct xdp_options {
-        pub flags: crate::__u32,
-    }
-
-    pub struct xdp_desc {
-        pub addr: crate::__u64,
-        pub len: crate::__u32,
-        pub options: crate::__u32,
-    }
-
-    pub struct xsk_tx_metadata_completion {
-        pub tx_timestamp: crate::__u64,
-    }
-
-    pub struct xsk_tx_metadata_request {
-        pub csum_start: __u16,
-        pub csum_offset: __u16,
-    }
-
     // linux/nsfs.h
     pub struct mnt_ns_info {
         pub size: crate::__u32,
@@ -963,12 +867,6 @@ s! {
         pad: Padding<[c_long; 4]>,
     }
 
-    pub struct hwtstamp_config {
-        pub flags: c_int,
-        pub tx_type: c_int,
-        pub rx_filter: c_int,
-    }
-
     pub struct sched_attr {
         pub size: __u32,
         pub sched_policy: __u32,
@@ -978,6 +876,8 @@ s! {
         pub sched_runtime: crate::__u64,
         pub sched_deadline: crate::__u64,
         pub sched_period: crate::__u64,
+        pub sched_util_min: __u32,
+        pub sched_util_max: __u32,
     }
 
     // linux/fcntl.h
@@ -1052,12 +952,6 @@ s_no_extra_traits! {
         pub iv: [c_uchar; 0],
     }
 
-    // linux/net_tstamp.h
-    pub struct sock_txtime {
-        pub clockid: crate::clockid_t,
-        pub flags: __u32,
-    }
-
     // linux/wireless.h
     pub union iwreq_data {
         pub name: [c_char; crate::IFNAMSIZ],
@@ -1113,17 +1007,6 @@ s_no_extra_traits! {
         pub flags: c_uint,
         pub anonymous_2: __c_anonymous_ptp_perout_request_2,
     }
-
-    // linux/if_xdp.h
-    pub struct xsk_tx_metadata {
-        pub flags: crate::__u64,
-        pub xsk_tx_metadata_union: __c_anonymous_xsk_tx_metadata_union,
-    }
-
-    pub union __c_anonymous_xsk_tx_metadata_union {
-        pub request: xsk_tx_metadata_request,
-        pub completion: xsk_tx_metadata_completion,
-    }
 }
 
 pub const POSIX_SPAWN_USEVFORK: c_short = 64;
@@ -1528,161 +1411,6 @@ pub const POSIX_SPAWN_SETSIGMASK: c_short = 0x08;
 pub const POSIX_SPAWN_SETSCHEDPARAM: c_short = 0x10;
 pub const POSIX_SPAWN_SETSCHEDULER: c_short = 0x20;
 
-// linux/netfilter/nfnetlink.h
-pub const NFNLGRP_NONE: c_int = 0;
-pub const NFNLGRP_CONNTRACK_NEW: c_int = 1;
-pub const NFNLGRP_CONNTRACK_UPDATE: c_int = 2;
-pub const NFNLGRP_CONNTRACK_DESTROY: c_int = 3;
-pub const NFNLGRP_CONNTRACK_EXP_NEW: c_int = 4;
-pub const NFNLGRP_CONNTRACK_EXP_UPDATE: c_int = 5;
-pub const NFNLGRP_CONNTRACK_EXP_DESTROY: c_int = 6;
-pub const NFNLGRP_NFTABLES: c_int = 7;
-pub const NFNLGRP_ACCT_QUOTA: c_int = 8;
-pub const NFNLGRP_NFTRACE: c_int = 9;
-
-pub const NFNETLINK_V0: c_int = 0;
-
-pub const NFNL_SUBSYS_NONE: c_int = 0;
-pub const NFNL_SUBSYS_CTNETLINK: c_int = 1;
-pub const NFNL_SUBSYS_CTNETLINK_EXP: c_int = 2;
-pub const NFNL_SUBSYS_QUEUE: c_int = 3;
-pub const NFNL_SUBSYS_ULOG: c_int = 4;
-pub const NFNL_SUBSYS_OSF: c_int = 5;
-pub const NFNL_SUBSYS_IPSET: c_int = 6;
-pub const NFNL_SUBSYS_ACCT: c_int = 7;
-pub const NFNL_SUBSYS_CTNETLINK_TIMEOUT: c_int = 8;
-pub const NFNL_SUBSYS_CTHELPER: c_int = 9;
-pub const NFNL_SUBSYS_NFTABLES: c_int = 10;
-pub const NFNL_SUBSYS_NFT_COMPAT: c_int = 11;
-pub const NFNL_SUBSYS_HOOK: c_int = 12;
-
-/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
-/// for details.
-pub const NFNL_SUBSYS_COUNT: c_int = 13;
-
-pub const NFNL_MSG_BATCH_BEGIN: c_int = crate::NLMSG_MIN_TYPE;
-pub const NFNL_MSG_BATCH_END: c_int = crate::NLMSG_MIN_TYPE + 1;
-
-pub const NFNL_BATCH_UNSPEC: c_int = 0;
-pub const NFNL_BATCH_GENID: c_int = 1;
-
-// linux/netfilter/nfnetlink_log.h
-pub const NFULNL_MSG_PACKET: c_int = 0;
-pub const NFULNL_MSG_CONFIG: c_int = 1;
-
-pub const NFULA_VLAN_UNSPEC: c_int = 0;
-pub const NFULA_VLAN_PROTO: c_int = 1;
-pub const NFULA_VLAN_TCI: c_int = 2;
-
-pub const NFULA_UNSPEC: c_int = 0;
-pub const NFULA_PACKET_HDR: c_int = 1;
-pub const NFULA_MARK: c_int = 2;
-pub const NFULA_TIMESTAMP: c_int = 3;
-pub const NFULA_IFINDEX_INDEV: c_int = 4;
-pub const NFULA_IFINDEX_OUTDEV: c_int = 5;
-pub const NFULA_IFINDEX_PHYSINDEV: c_int = 6;
-pub const NFULA_IFINDEX_PHYSOUTDEV: c_int = 7;
-pub const NFULA_HWADDR: c_int = 8;
-pub const NFULA_PAYLOAD: c_int = 9;
-pub const NFULA_PREFIX: c_int = 10;
-pub const NFULA_UID: c_int = 11;
-pub const NFULA_SEQ: c_int = 12;
-pub const NFULA_SEQ_GLOBAL: c_int = 13;
-pub const NFULA_GID: c_int = 14;
-pub const NFULA_HWTYPE: c_int = 15;
-pub const NFULA_HWHEADER: c_int = 16;
-pub const NFULA_HWLEN: c_int = 17;
-pub const NFULA_CT: c_int = 18;
-pub const NFULA_CT_INFO: c_int = 19;
-pub const NFULA_VLAN: c_int = 20;
-pub const NFULA_L2HDR: c_int = 21;
-
-pub const NFULNL_CFG_CMD_NONE: c_int = 0;
-pub const NFULNL_CFG_CMD_BIND: c_int = 1;
-pub const NFULNL_CFG_CMD_UNBIND: c_int = 2;
-pub const NFULNL_CFG_CMD_PF_BIND: c_int = 3;
-pub const NFULNL_CFG_CMD_PF_UNBIND: c_int = 4;
-
-pub const NFULA_CFG_UNSPEC: c_int = 0;
-pub const NFULA_CFG_CMD: c_int = 1;
-pub const NFULA_CFG_MODE: c_int = 2;
-pub const NFULA_CFG_NLBUFSIZ: c_int = 3;
-pub const NFULA_CFG_TIMEOUT: c_int = 4;
-pub const NFULA_CFG_QTHRESH: c_int = 5;
-pub const NFULA_CFG_FLAGS: c_int = 6;
-
-pub const NFULNL_COPY_NONE: c_int = 0x00;
-pub const NFULNL_COPY_META: c_int = 0x01;
-pub const NFULNL_COPY_PACKET: c_int = 0x02;
-
-pub const NFULNL_CFG_F_SEQ: c_int = 0x0001;
-pub const NFULNL_CFG_F_SEQ_GLOBAL: c_int = 0x0002;
-pub const NFULNL_CFG_F_CONNTRACK: c_int = 0x0004;
-
-// linux/netfilter/nfnetlink_queue.h
-pub const NFQNL_MSG_PACKET: c_int = 0;
-pub const NFQNL_MSG_VERDICT: c_int = 1;
-pub const NFQNL_MSG_CONFIG: c_int = 2;
-pub const NFQNL_MSG_VERDICT_BATCH: c_int = 3;
-
-pub const NFQA_UNSPEC: c_int = 0;
-pub const NFQA_PACKET_HDR: c_int = 1;
-pub const NFQA_VERDICT_HDR: c_int = 2;
-pub const NFQA_MARK: c_int = 3;
-pub const NFQA_TIMESTAMP: c_int = 4;
-pub const NFQA_IFINDEX_INDEV: c_int = 5;
-pub const NFQA_IFINDEX_OUTDEV: c_int = 6;
-pub const NFQA_IFINDEX_PHYSINDEV: c_int = 7;
-pub const NFQA_IFINDEX_PHYSOUTDEV: c_int = 8;
-pub const NFQA_HWADDR: c_int = 9;
-pub const NFQA_PAYLOAD: c_int = 10;
-pub const NFQA_CT: c_int = 11;
-pub const NFQA_CT_INFO: c_int = 12;
-pub const NFQA_CAP_LEN: c_int = 13;
-pub const NFQA_SKB_INFO: c_int = 14;
-pub const NFQA_EXP: c_int = 15;
-pub const NFQA_UID: c_int = 16;
-pub const NFQA_GID: c_int = 17;
-pub const NFQA_SECCTX: c_int = 18;
-pub const NFQA_VLAN: c_int = 19;
-pub const NFQA_L2HDR: c_int = 20;
-pub const NFQA_PRIORITY: c_int = 21;
-
-pub const NFQA_VLAN_UNSPEC: c_int = 0;
-pub const NFQA_VLAN_PROTO: c_int = 1;
-pub const NFQA_VLAN_TCI: c_int = 2;
-
-pub const NFQNL_CFG_CMD_NONE: c_int = 0;
-pub const NFQNL_CFG_CMD_BIND: c_int = 1;
-pub const NFQNL_CFG_CMD_UNBIND: c_int = 2;
-pub const NFQNL_CFG_CMD_PF_BIND: c_int = 3;
-pub const NFQNL_CFG_CMD_PF_UNBIND: c_int = 4;
-
-pub const NFQNL_COPY_NONE: c_int = 0;
-pub const NFQNL_COPY_META: c_int = 1;
-pub const NFQNL_COPY_PACKET: c_int = 2;
-
-pub const NFQA_CFG_UNSPEC: c_int = 0;
-pub const NFQA_CFG_CMD: c_int = 1;
-pub const NFQA_CFG_PARAMS: c_int = 2;
-pub const NFQA_CFG_QUEUE_MAXLEN: c_int = 3;
-pub const NFQA_CFG_MASK: c_int = 4;
-pub const NFQA_CFG_FLAGS: c_int = 5;
-
-pub const NFQA_CFG_F_FAIL_OPEN: c_int = 0x0001;
-pub const NFQA_CFG_F_CONNTRACK: c_int = 0x0002;
-pub const NFQA_CFG_F_GSO: c_int = 0x0004;
-pub const NFQA_CFG_F_UID_GID: c_int = 0x0008;
-pub const NFQA_CFG_F_SECCTX: c_int = 0x0010;
-
-/// Constants may change across releases. See the [usage guidelines](crate#usage-guidelines)
-/// for details.
-pub const NFQA_CFG_F_MAX: c_int = 0x0020;
-
-pub const NFQA_SKB_CSUMNOTREADY: c_int = 0x0001;
-pub const NFQA_SKB_GSO: c_int = 0x0002;
-pub const NFQA_SKB_CSUM_NOTVERIFIED: c_int = 0x0004;
-
 // linux/genetlink.h
 
 pub const GENL_NAMSIZ: c_int = 16;
@@ -2373,97 +2101,10 @@ pub const RTNLGRP_MCTP_IFADDR: c_uint = 0x22;
 pub const RTNLGRP_TUNNEL: c_uint = 0x23;
 pub const RTNLGRP_STATS: c_uint = 0x24;
 
-// linux/cn_proc.h
-c_enum! {
-    pub enum proc_cn_mcast_op {
-        pub PROC_CN_MCAST_LISTEN = 1,
-        pub PROC_CN_MCAST_IGNORE = 2,
-    }
-
-    pub enum proc_cn_event {
-        pub PROC_EVENT_NONE = 0x00000000,
-        pub PROC_EVENT_FORK = 0x00000001,
-        pub PROC_EVENT_EXEC = 0x00000002,
-        pub PROC_EVENT_UID = 0x00000004,
-        pub PROC_EVENT_GID = 0x00000040,
-        pub PROC_EVENT_SID = 0x00000080,
-        pub PROC_EVENT_PTRACE = 0x00000100,
-        pub PROC_EVENT_COMM = 0x00000200,
-        pub PROC_EVENT_NONZERO_EXIT = 0x20000000,
-        pub PROC_EVENT_COREDUMP = 0x40000000,
-        pub PROC_EVENT_EXIT = 0x80000000,
-    }
-}
-
-// linux/connector.h
-pub const CN_IDX_PROC: c_uint = 0x1;
-pub const CN_VAL_PROC: c_uint = 0x1;
-pub const CN_IDX_CIFS: c_uint = 0x2;
-pub const CN_VAL_CIFS: c_uint = 0x1;
-pub const CN_W1_IDX: c_uint = 0x3;
-pub const CN_W1_VAL: c_uint = 0x1;
-pub const CN_IDX_V86D: c_uint = 0x4;
-pub const CN_VAL_V86D_UVESAFB: c_uint = 0x1;
-pub const CN_IDX_BB: c_uint = 0x5;
-pub const CN_DST_IDX: c_uint = 0x6;
-pub const CN_DST_VAL: c_uint = 0x1;
-pub const CN_IDX_DM: c_uint = 0x7;
-pub const CN_VAL_DM_USERSPACE_LOG: c_uint = 0x1;
-pub const CN_IDX_DRBD: c_uint = 0x8;
-pub const CN_VAL_DRBD: c_uint = 0x1;
-pub const CN_KVP_IDX: c_uint = 0x9;
-pub const CN_KVP_VAL: c_uint = 0x1;
-pub const CN_VSS_IDX: c_uint = 0xA;
-pub const CN_VSS_VAL: c_uint = 0x1;
-
 // linux/module.h
 pub const MODULE_INIT_IGNORE_MODVERSIONS: c_uint = 0x0001;
 pub const MODULE_INIT_IGNORE_VERMAGIC: c_uint = 0x0002;
 
-// linux/net_tstamp.h
-pub const SOF_TIMESTAMPING_TX_HARDWARE: c_uint = 1 << 0;
-pub const SOF_TIMESTAMPING_TX_SOFTWARE: c_uint = 1 << 1;
-pub const SOF_TIMESTAMPING_RX_HARDWARE: c_uint = 1 << 2;
-pub const SOF_TIMESTAMPING_RX_SOFTWARE: c_uint = 1 << 3;
-pub const SOF_TIMESTAMPING_SOFTWARE: c_uint = 1 << 4;
-pub const SOF_TIMESTAMPING_SYS_HARDWARE: c_uint = 1 << 5;
-pub const SOF_TIMESTAMPING_RAW_HARDWARE: c_uint = 1 << 6;
-pub const SOF_TIMESTAMPING_OPT_ID: c_uint = 1 << 7;
-pub const SOF_TIMESTAMPING_TX_SCHED: c_uint = 1 << 8;
-pub const SOF_TIMESTAMPING_TX_ACK: c_uint = 1 << 9;
-pub const SOF_TIMESTAMPING_OPT_CMSG: c_uint = 1 << 10;
-pub const SOF_TIMESTAMPING_OPT_TSONLY: c_uint = 1 << 11;
-pub const SOF_TIMESTAMPING_OPT_STATS: c_uint = 1 << 12;
-pub const SOF_TIMESTAMPING_OPT_PKTINFO: c_uint = 1 << 13;
-pub const SOF_TIMESTAMPING_OPT_TX_SWHW: c_uint = 1 << 14;
-pub const SOF_TIMESTAMPING_BIND_PHC: c_uint = 1 << 15;
-pub const SOF_TIMESTAMPING_OPT_ID_TCP: c_uint = 1 << 16;
-pub const SOF_TIMESTAMPING_OPT_RX_FILTER: c_uint = 1 << 17;
-pub const SOF_TXTIME_DEADLINE_MODE: u32 = 1 << 0;
-pub const SOF_TXTIME_REPORT_ERRORS: u32 = 1 << 1;
-
-pub const HWTSTAMP_TX_OFF: c_uint = 0;
-pub const HWTSTAMP_TX_ON: c_uint = 1;
-pub const HWTSTAMP_TX_ONESTEP_SYNC: c_uint = 2;
-pub const HWTSTAMP_TX_ONESTEP_P2P: c_uint = 3;
-
-pub const HWTSTAMP_FILTER_NONE: c_uint = 0;
-pub const HWTSTAMP_FILTER_ALL: c_uint = 1;
-pub const HWTSTAMP_FILTER_SOME: c_uint = 2;
-pub const HWTSTAMP_FILTER_PTP_V1_L4_EVENT: c_uint = 3;
-pub const HWTSTAMP_FILTER_PTP_V1_L4_SYNC: c_uint = 4;
-pub const HWTSTAMP_FILTER_PTP_V1_L4_DELAY_REQ: c_uint = 5;
-pub const HWTSTAMP_FILTER_PTP_V2_L4_EVENT: c_uint = 6;
-pub const HWTSTAMP_FILTER_PTP_V2_L4_SYNC: c_uint = 7;
-pub const HWTSTAMP_FILTER_PTP_V2_L4_DELAY_REQ: c_uint = 8;
-pub const HWTSTAMP_FILTER_PTP_V2_L2_EVENT: c_uint = 9;
-pub const HWTSTAMP_FILTER_PTP_V2_L2_SYNC: c_uint = 10;
-pub const HWTSTAMP_FILTER_PTP_V2_L2_DELAY_REQ: c_uint = 11;
-pub const HWTSTAMP_FILTER_PTP_V2_EVENT: c_uint = 12;
-pub const HWTSTAMP_FILTER_PTP_V2_SYNC: c_uint = 13;
-pub const HWTSTAMP_FILTER_PTP_V2_DELAY_REQ: c_uint = 14;
-pub const HWTSTAMP_FILTER_NTP_ALL: c_uint = 15;
-
 // linux/ptp_clock.h
 pub const PTP_MAX_SAMPLES: c_uint = 25; // Maximum allowed offset measurement samples.
 
@@ -2498,6 +2139,7 @@ pub const PTP_PF_PHYSYNC: c_uint = 3;
 // linux/socket.h
 
 pub const SOL_TLS: c_int = 282;
+pub const SOL_MPTCP: c_int = 284;
 
 // linux/if_alg.h
 pub const ALG_SET_KEY: c_int = 1;
@@ -3025,14 +2667,6 @@ pub const LINUX_REBOOT_CMD_RESTART2: c_int = u32_cast_int(0xA1B2C3D4);
 pub const LINUX_REBOOT_CMD_SW_SUSPEND: c_int = u32_cast_int(0xD000FCE2);
 pub const LINUX_REBOOT_CMD_KEXEC: c_int = 0x45584543;
 
-// linux/errqueue.h
-pub const SO_EE_ORIGIN_NONE: u8 = 0;
-pub const SO_EE_ORIGIN_LOCAL: u8 = 1;
-pub const SO_EE_ORIGIN_ICMP: u8 = 2;
-pub const SO_EE_ORIGIN_ICMP6: u8 = 3;
-pub const SO_EE_ORIGIN_TXSTATUS: u8 = 4;
-pub const SO_EE_ORIGIN_TIMESTAMPING: u8 = SO_EE_ORIGIN_TXSTATUS;
-
 /* DCCP socket options */
 pub const DCCP_SOCKOPT_PACKET_SIZE: c_int = 1;
 pub const DCCP_SOCKOPT_SERVICE: c_int = 2;
@@ -3267,6 +2901,8 @@ const PF_SUSPEND_TASK_UINT: c_uint = 0x80000000;
 pub const CLONE_CLEAR_SIGHAND: c_ulonglong = 0x100000000;
 pub const CLONE_INTO_CGROUP: c_ulonglong = 0x200000000;
 
+pub const SCHED_EXT: c_int = 7;
+
 pub const SCHED_FLAG_RESET_ON_FORK: c_int = 0x01;
 pub const SCHED_FLAG_RECLAIM: c_int = 0x02;
 pub const SCHED_FLAG_DL_OVERRUN: c_int = 0x04;
@@ -3275,47 +2911,6 @@ pub const SCHED_FLAG_KEEP_PARAMS: c_int = 0x10;
 pub const SCHED_FLAG_UTIL_CLAMP_MIN: c_int = 0x20;
 pub const SCHED_FLAG_UTIL_CLAMP_MAX: c_int = 0x40;
 
-// linux/if_xdp.h
-pub const XDP_SHARED_UMEM: crate::__u16 = 1 << 0;
-pub const XDP_COPY: crate::__u16 = 1 << 1;
-pub const XDP_ZEROCOPY: crate::__u16 = 1 << 2;
-pub const XDP_USE_NEED_WAKEUP: crate::__u16 = 1 << 3;
-pub const XDP_USE_SG: crate::__u16 = 1 << 4;
-
-pub const XDP_UMEM_UNALIGNED_CHUNK_FLAG: crate::__u32 = 1 << 0;
-
-pub const XDP_RING_NEED_WAKEUP: crate::__u32 = 1 << 0;
-
-pub const XDP_MMAP_OFFSETS: c_int = 1;
-pub const XDP_RX_RING: c_int = 2;
-pub const XDP_TX_RING: c_int = 3;
-pub const XDP_UMEM_REG: c_int = 4;
-pub const XDP_UMEM_FILL_RING: c_int = 5;
-pub const XDP_UMEM_COMPLETION_RING: c_int = 6;
-pub const XDP_STATISTICS: c_int = 7;
-pub const XDP_OPTIONS: c_int = 8;
-
-pub const XDP_OPTIONS_ZEROCOPY: crate::__u32 = 1 << 0;
-
-pub const XDP_PGOFF_RX_RING: crate::off_t = 0;
-pub const XDP_PGOFF_TX_RING: crate::off_t = 0x80000000u32 as crate::off_t;
-pub const XDP_UMEM_PGOFF_FILL_RING: crate::c_ulonglong = 0x100000000;
-pub const XDP_UMEM_PGOFF_COMPLETION_RING: crate::c_ulonglong = 0x180000000;
-
-pub const XSK_UNALIGNED_BUF_OFFSET_SHIFT: crate::c_int = 48;
-pub const XSK_UNALIGNED_BUF_ADDR_MASK: crate::c_ulonglong =
-    (1 << XSK_UNALIGNED_BUF_OFFSET_SHIFT) - 1;
-
-pub const XDP_PKT_CONTD: crate::__u32 = 1 << 0;
-
-pub const XDP_UMEM_TX_SW_CSUM: crate::__u32 = 1 << 1;
-pub const XDP_UMEM_TX_METADATA_LEN: crate::__u32 = 1 << 2;
-
-pub const XDP_TXMD_FLAGS_TIMESTAMP: crate::__u32 = 1 << 0;
-pub const XDP_TXMD_FLAGS_CHECKSUM: crate::__u32 = 1 << 1;
-
-pub const XDP_TX_METADATA: crate::__u32 = 1 << 1;
-
 pub const SOL_XDP: c_int = 283;
 
 pub const SCHED_FLAG_KEEP_ALL: c_int = SCHED_FLAG_KEEP_POLICY | SCHED_FLAG_KEEP_PARAMS;
@@ -3338,8 +2933,47 @@ pub const SI_DETHREAD: c_int = -7;
 pub const TRAP_PERF: c_int = 6;
 
 f! {
-    pub unsafe fn SO_EE_OFFENDER(ee: *const crate::sock_extended_err) -> *mut crate::sockaddr {
-        ee.offset(1) as *mut crate::sockaddr
+    // glibc only gained these wrappers in 2.41 and musl doesn't have them.
+    pub unsafe fn sched_setattr(pid: crate::pid_t, attr: *mut sched_attr, flags: c_uint) -> c_int {
+        crate::syscall(crate::SYS_sched_setattr as c_long, pid, attr, flags) as c_int
+    }
+
+    pub unsafe fn sched_getattr(
+        pid: crate::pid_t,
+        attr: *mut sched_attr,
+        size: c_uint,
+        flags: c_uint,
+    ) -> c_int {
+        crate::syscall(crate::SYS_sched_getattr as c_long, pid, attr, size, flags) as c_int
+    }
+
+    // No libc provides wrappers for these.
+    pub unsafe fn ioprio_get(which: c_int, who: c_int) -> c_int {
+        crate::syscall(crate::SYS_ioprio_get as c_long, which, who) as c_int
+    }
+
+    pub unsafe fn ioprio_set(which: c_int, who: c_int, ioprio: c_int) -> c_int {
+        crate::syscall(crate::SYS_ioprio_set as c_long, which, who, ioprio) as c_int
+    }
+
+    /// Queries the hardware capabilities of the CPUs in `cpus`, or of all online CPUs if `cpus`
+    /// is null. glibc 2.40 and later also provide this as `__riscv_hwprobe`.
+    #[cfg(target_arch = "riscv64")]
+    pub unsafe fn riscv_hwprobe(
+        pairs: *mut crate::riscv_hwprobe,
+        pair_count: size_t,
+        cpusetsize: size_t,
+        cpus: *mut crate::cpu_set_t,
+        flags: c_uint,
+    ) -> c_int {
+        crate::syscall(
+            crate::SYS_riscv_hwprobe,
+            pairs,
+            pair_count,
+            cpusetsize,
+            cpus,
+            flags,
+        ) as c_int
     }
 
     pub unsafe fn BPF_CLASS(code: __u32) -> __u32 {
@@ -224,40 +224,6 @@ pub const ENOTRECOVERABLE: c_int = 131;
 pub const ERFKILL: c_int = 132;
 pub const EHWPOISON: c_int = 133;
 
-// bits/hwcap.h
-pub const HWCAP_FP: c_ulong = 1 << 0;
-pub const HWCAP_ASIMD: c_ulong = 1 << 1;
-pub const HWCAP_EVTSTRM: c_ulong = 1 << 2;
-pub const HWCAP_AES: c_ulong = 1 << 3;
-pub const HWCAP_PMULL: c_ulong = 1 << 4;
-pub const HWCAP_SHA1: c_ulong = 1 << 5;
-pub const HWCAP_SHA2: c_ulong = 1 << 6;
-pub const HWCAP_CRC32: c_ulong = 1 << 7;
-pub const HWCAP_ATOMICS: c_ulong = 1 << 8;
-pub const HWCAP_FPHP: c_ulong = 1 << 9;
-pub const HWCAP_ASIMDHP: c_ulong = 1 << 10;
-pub const HWCAP_CPUID: c_ulong = 1 << 11;
-pub const HWCAP_ASIMDRDM: c_ulong = 1 << 12;
-pub const HWCAP_JSCVT: c_ulong = 1 << 13;
-pub const HWCAP_FCMA: c_ulong = 1 << 14;
-pub const HWCAP_LRCPC: c_ulong = 1 << 15;
-pub const HWCAP_DCPOP: c_ulong = 1 << 16;
-pub const HWCAP_SHA3: c_ulong = 1 << 17;
-pub const HWCAP_SM3: c_ulong = 1 << 18;
-pub const HWCAP_SM4: c_ulong = 1 << 19;
-pub const HWCAP_ASIMDDP: c_ulong = 1 << 20;
-pub const HWCAP_SHA512: c_ulong = 1 << 21;
-pub const HWCAP_SVE: c_ulong = 1 << 22;
-pub const HWCAP_ASIMDFHM: c_ulong = 1 << 23;
-pub const HWCAP_DIT: c_ulong = 1 << 24;
-pub const HWCAP_USCAT: c_ulong = 1 << 25;
-pub const HWCAP_ILRCPC: c_ulong = 1 << 26;
-pub const HWCAP_FLAGM: c_ulong = 1 << 27;
-pub const HWCAP_SSBS: c_ulong = 1 << 28;
-pub const HWCAP_SB: c_ulong = 1 << 29;
-pub const HWCAP_PACA: c_ulong = 1 << 30;
-pub const HWCAP_PACG: c_ulong = 1 << 31;
-
 pub const MAP_ANON: c_int = 0x0020;
 pub const MAP_GROWSDOWN: c_int = 0x0100;
 pub const MAP_DENYWRITE: c_int = 0x0800;
@@ -164,6 +164,7 @@ pub const SYS_getsockopt: c_long = 209;
 pub const SYS_clone: c_long = 220;
 pub const SYS_execve: c_long = 221;
 pub const SYS_exit: c_long = 93;
+pub const SYS_riscv_hwprobe: c_long = 258;
 pub const SYS_wait4: c_long = 260;
 pub const SYS_kill: c_long = 129;
 pub const SYS_uname: c_long = 160;
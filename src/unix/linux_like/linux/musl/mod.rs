@@ -57,83 +57,6 @@ cfg_if! {
     }
 }
 
-impl siginfo_t {
-    pub unsafe fn si_addr(&self) -> *mut c_void {
-        #[repr(C)]
-        struct siginfo_sigfault {
-            _si_signo: c_int,
-            _si_errno: c_int,
-            _si_code: c_int,
-            si_addr: *mut c_void,
-        }
-        (*(self as *const siginfo_t).cast::<siginfo_sigfault>()).si_addr
-    }
-
-    pub unsafe fn si_value(&self) -> crate::sigval {
-        #[repr(C)]
-        struct siginfo_si_value {
-            _si_signo: c_int,
-            _si_errno: c_int,
-            _si_code: c_int,
-            _si_timerid: c_int,
-            _si_overrun: c_int,
-            si_value: crate::sigval,
-        }
-        (*(self as *const siginfo_t).cast::<siginfo_si_value>()).si_value
-    }
-}
-
-s_no_extra_traits! {
-    // Internal, for casts to access union fields
-    struct sifields_sigchld {
-        si_pid: crate::pid_t,
-        si_uid: crate::uid_t,
-        si_status: c_int,
-        si_utime: c_long,
-        si_stime: c_long,
-    }
-
-    // Internal, for casts to access union fields
-    union sifields {
-        _align_pointer: *mut c_void,
-        sigchld: sifields_sigchld,
-    }
-
-    // Internal, for casts to access union fields. Note that some variants
-    // of sifields start with a pointer, which makes the alignment of
-    // sifields vary on 32-bit and 64-bit architectures.
-    struct siginfo_f {
-        _siginfo_base: [c_int; 3],
-        sifields: sifields,
-    }
-}
-
-impl siginfo_t {
-    unsafe fn sifields(&self) -> &sifields {
-        &(*(self as *const siginfo_t).cast::<siginfo_f>()).sifields
-    }
-
-    pub unsafe fn si_pid(&self) -> crate::pid_t {
-        self.sifields().sigchld.si_pid
-    }
-
-    pub unsafe fn si_uid(&self) -> crate::uid_t {
-        self.sifields().sigchld.si_uid
-    }
-
-    pub unsafe fn si_status(&self) -> c_int {
-        self.sifields().sigchld.si_status
-    }
-
-    pub unsafe fn si_utime(&self) -> c_long {
-        self.sifields().sigchld.si_utime
-    }
-
-    pub unsafe fn si_stime(&self) -> c_long {
-        self.sifields().sigchld.si_stime
-    }
-}
-
 s! {
     #[repr(align(8))]
     pub struct fanotify_event_metadata {
@@ -281,19 +204,6 @@ s! {
         pub e_exit: c_short,
     }
 
-    pub struct Elf64_Chdr {
-        pub ch_type: crate::Elf64_Word,
-        pub ch_reserved: crate::Elf64_Word,
-        pub ch_size: crate::Elf64_Xword,
-        pub ch_addralign: crate::Elf64_Xword,
-    }
-
-    pub struct Elf32_Chdr {
-        pub ch_type: crate::Elf32_Word,
-        pub ch_size: crate::Elf32_Word,
-        pub ch_addralign: crate::Elf32_Word,
-    }
-
     pub struct timex {
         pub modes: c_uint,
         pub offset: c_long,
@@ -657,6 +567,9 @@ pub const TCSAFLUSH: c_int = 2;
 pub const RTLD_GLOBAL: c_int = 0x100;
 pub const RTLD_NOLOAD: c_int = 0x4;
 
+// musl only supports this `dlinfo` request.
+pub const RTLD_DI_LINKMAP: c_int = 2;
+
 pub const CLOCK_SGI_CYCLE: crate::clockid_t = 10;
 
 pub const B0: crate::speed_t = 0o000000;
@@ -798,6 +711,7 @@ extern "C" {
         flags: c_int,
     ) -> ssize_t;
     pub fn getauxval(type_: c_ulong) -> c_ulong;
+    pub fn dlinfo(handle: *mut c_void, request: c_int, info: *mut c_void) -> c_int;
     pub fn renameat2(
         olddirfd: c_int,
         oldpath: *const c_char,
@@ -844,41 +844,6 @@ pub const PF_R: u32 = 1 << 2;
 pub const PF_MASKOS: u32 = 0x0ff00000;
 pub const PF_MASKPROC: u32 = 0xf0000000;
 
-// elf.h - Legal values for a_type (entry type).
-pub const AT_NULL: c_ulong = 0;
-pub const AT_IGNORE: c_ulong = 1;
-pub const AT_EXECFD: c_ulong = 2;
-pub const AT_PHDR: c_ulong = 3;
-pub const AT_PHENT: c_ulong = 4;
-pub const AT_PHNUM: c_ulong = 5;
-pub const AT_PAGESZ: c_ulong = 6;
-pub const AT_BASE: c_ulong = 7;
-pub const AT_FLAGS: c_ulong = 8;
-pub const AT_ENTRY: c_ulong = 9;
-pub const AT_NOTELF: c_ulong = 10;
-pub const AT_UID: c_ulong = 11;
-pub const AT_EUID: c_ulong = 12;
-pub const AT_GID: c_ulong = 13;
-pub const AT_EGID: c_ulong = 14;
-pub const AT_PLATFORM: c_ulong = 15;
-pub const AT_HWCAP: c_ulong = 16;
-pub const AT_CLKTCK: c_ulong = 17;
-
-pub const AT_SECURE: c_ulong = 23;
-pub const AT_BASE_PLATFORM: c_ulong = 24;
-pub const AT_RANDOM: c_ulong = 25;
-pub const AT_HWCAP2: c_ulong = 26;
-
-pub const AT_HWCAP3: c_ulong = 29;
-pub const AT_HWCAP4: c_ulong = 30;
-pub const AT_EXECFN: c_ulong = 31;
-
-// defined in arch/<arch>/include/uapi/asm/auxvec.h but has the same value
-// wherever it is defined.
-pub const AT_SYSINFO_EHDR: c_ulong = 33;
-#[cfg(not(target_env = "uclibc"))]
-pub const AT_MINSIGSTKSZ: c_ulong = 51;
-
 pub const GLOB_ERR: c_int = 1 << 0;
 pub const GLOB_MARK: c_int = 1 << 1;
 pub const GLOB_NOSORT: c_int = 1 << 2;
@@ -1433,6 +1398,14 @@ pub const NT_PRFPXREG: c_int = 20;
 pub const MS_NOUSER: c_ulong = 1 << 31;
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno_location() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno_location() = value }
+    }
+
     pub unsafe fn CMSG_NXTHDR(
         mhdr: *const crate::msghdr,
         cmsg: *const crate::cmsghdr,
@@ -1470,9 +1443,23 @@ f! {
     }
 
     pub unsafe fn CPU_ALLOC_SIZE(count: c_int) -> size_t {
-        let _dummy: cpu_set_t = mem::zeroed();
-        let size_in_bits = 8 * size_of_val(&_dummy.bits[0]);
-        ((count as size_t + size_in_bits - 1) / 8) as size_t
+        let size_in_bits = c_ulong::BITS as usize;
+        (count as size_t + size_in_bits - 1) / size_in_bits * size_of::<c_ulong>()
+    }
+
+    pub unsafe fn CPU_ALLOC(count: c_int) -> *mut cpu_set_t {
+        #[cfg(target_env = "gnu")]
+        let cpuset = crate::__sched_cpualloc(count as size_t);
+        #[cfg(not(target_env = "gnu"))]
+        let cpuset = crate::calloc(1, CPU_ALLOC_SIZE(count)).cast();
+        cpuset
+    }
+
+    pub unsafe fn CPU_FREE(cpuset: *mut cpu_set_t) -> () {
+        #[cfg(target_env = "gnu")]
+        crate::__sched_cpufree(cpuset);
+        #[cfg(not(target_env = "gnu"))]
+        crate::free(cpuset.cast());
     }
 
     pub unsafe fn CPU_ZERO(cpuset: &mut cpu_set_t) -> () {
@@ -1497,13 +1484,42 @@ f! {
         0 != (cpuset.bits[idx] & (1 << offset))
     }
 
-    pub unsafe fn CPU_COUNT_S(size: usize, cpuset: &cpu_set_t) -> c_int {
-        let mut s: u32 = 0;
-        let size_of_mask = size_of_val(&cpuset.bits[0]);
-        for i in &cpuset.bits[..(size / size_of_mask)] {
-            s += i.count_ones();
+    pub unsafe fn CPU_ZERO_S(size: usize, cpuset: *mut cpu_set_t) -> () {
+        ptr::write_bytes(cpuset.cast::<u8>(), 0, size);
+    }
+
+    pub unsafe fn CPU_SET_S(cpu: usize, size: usize, cpuset: *mut cpu_set_t) -> () {
+        let size_in_bits = c_ulong::BITS as usize;
+        if cpu / 8 < size {
+            let (idx, offset) = (cpu / size_in_bits, cpu % size_in_bits);
+            *cpuset.cast::<c_ulong>().add(idx) |= 1 << offset;
+        }
+    }
+
+    pub unsafe fn CPU_CLR_S(cpu: usize, size: usize, cpuset: *mut cpu_set_t) -> () {
+        let size_in_bits = c_ulong::BITS as usize;
+        if cpu / 8 < size {
+            let (idx, offset) = (cpu / size_in_bits, cpu % size_in_bits);
+            *cpuset.cast::<c_ulong>().add(idx) &= !(1 << offset);
         }
-        s as c_int
+    }
+
+    pub unsafe fn CPU_ISSET_S(cpu: usize, size: usize, cpuset: *const cpu_set_t) -> bool {
+        let size_in_bits = c_ulong::BITS as usize;
+        let (idx, offset) = (cpu / size_in_bits, cpu % size_in_bits);
+        cpu / 8 < size && 0 != (*cpuset.cast::<c_ulong>().add(idx) & (1 << offset))
+    }
+
+    pub unsafe fn CPU_COUNT_S(size: usize, cpuset: *const cpu_set_t) -> c_int {
+        #[cfg(target_env = "gnu")]
+        let s = crate::__sched_cpucount(size, cpuset);
+        #[cfg(not(target_env = "gnu"))]
+        let s = {
+            let words =
+                core::slice::from_raw_parts(cpuset.cast::<c_ulong>(), size / size_of::<c_ulong>());
+            words.iter().map(|w| w.count_ones()).sum::<u32>() as c_int
+        };
+        s
     }
 
     pub unsafe fn CPU_COUNT(cpuset: &cpu_set_t) -> c_int {
@@ -1514,6 +1530,72 @@ f! {
         set1.bits == set2.bits
     }
 
+    pub unsafe fn CPU_EQUAL_S(size: usize, set1: *const cpu_set_t, set2: *const cpu_set_t) -> bool {
+        let set1 = core::slice::from_raw_parts(set1.cast::<u8>(), size);
+        let set2 = core::slice::from_raw_parts(set2.cast::<u8>(), size);
+        set1 == set2
+    }
+
+    pub unsafe fn CPU_AND_S(
+        size: usize,
+        destset: *mut cpu_set_t,
+        srcset1: *const cpu_set_t,
+        srcset2: *const cpu_set_t,
+    ) -> () {
+        let dest = destset.cast::<c_ulong>();
+        let (src1, src2) = (srcset1.cast::<c_ulong>(), srcset2.cast::<c_ulong>());
+        for i in 0..size / size_of::<c_ulong>() {
+            *dest.add(i) = *src1.add(i) & *src2.add(i);
+        }
+    }
+
+    pub unsafe fn CPU_AND(destset: &mut cpu_set_t, srcset1: &cpu_set_t, srcset2: &cpu_set_t) -> () {
+        let srcs = srcset1.bits.iter().zip(&srcset2.bits);
+        for (dest, (src1, src2)) in destset.bits.iter_mut().zip(srcs) {
+            *dest = src1 & src2;
+        }
+    }
+
+    pub unsafe fn CPU_OR_S(
+        size: usize,
+        destset: *mut cpu_set_t,
+        srcset1: *const cpu_set_t,
+        srcset2: *const cpu_set_t,
+    ) -> () {
+        let dest = destset.cast::<c_ulong>();
+        let (src1, src2) = (srcset1.cast::<c_ulong>(), srcset2.cast::<c_ulong>());
+        for i in 0..size / size_of::<c_ulong>() {
+            *dest.add(i) = *src1.add(i) | *src2.add(i);
+        }
+    }
+
+    pub unsafe fn CPU_OR(destset: &mut cpu_set_t, srcset1: &cpu_set_t, srcset2: &cpu_set_t) -> () {
+        let srcs = srcset1.bits.iter().zip(&srcset2.bits);
+        for (dest, (src1, src2)) in destset.bits.iter_mut().zip(srcs) {
+            *dest = src1 | src2;
+        }
+    }
+
+    pub unsafe fn CPU_XOR_S(
+        size: usize,
+        destset: *mut cpu_set_t,
+        srcset1: *const cpu_set_t,
+        srcset2: *const cpu_set_t,
+    ) -> () {
+        let dest = destset.cast::<c_ulong>();
+        let (src1, src2) = (srcset1.cast::<c_ulong>(), srcset2.cast::<c_ulong>());
+        for i in 0..size / size_of::<c_ulong>() {
+            *dest.add(i) = *src1.add(i) ^ *src2.add(i);
+        }
+    }
+
+    pub unsafe fn CPU_XOR(destset: &mut cpu_set_t, srcset1: &cpu_set_t, srcset2: &cpu_set_t) -> () {
+        let srcs = srcset1.bits.iter().zip(&srcset2.bits);
+        for (dest, (src1, src2)) in destset.bits.iter_mut().zip(srcs) {
+            *dest = src1 ^ src2;
+        }
+    }
+
     pub unsafe fn IPTOS_TOS(tos: u8) -> u8 {
         tos & IPTOS_TOS_MASK
     }
@@ -1543,7 +1625,7 @@ f! {
     }
 
     pub unsafe fn ELF32_R_INFO(sym: Elf32_Word, t: Elf32_Word) -> Elf32_Word {
-        sym << (8 + t) & 0xff
+        (sym << 8) + (t & 0xff)
     }
 
     pub unsafe fn ELF64_R_SYM(val: Elf64_Xword) -> Elf64_Xword {
@@ -1555,7 +1637,7 @@ f! {
     }
 
     pub unsafe fn ELF64_R_INFO(sym: Elf64_Xword, t: Elf64_Xword) -> Elf64_Xword {
-        sym << (32 + t)
+        (sym << 32).wrapping_add(t)
     }
 
     pub const safe fn makedev(major: c_uint, minor: c_uint) -> crate::dev_t {
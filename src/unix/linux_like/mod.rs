@@ -269,6 +269,18 @@ cfg_if! {
                 pub len: c_ushort,
                 pub filter: *mut sock_filter,
             }
+
+            // linux/if_tun.h
+            pub struct tun_pi {
+                pub flags: crate::__u16,
+                pub proto: crate::__be16,
+            }
+
+            pub struct tun_filter {
+                pub flags: crate::__u16,
+                pub count: crate::__u16,
+                pub addr: [[crate::__u8; crate::ETH_ALEN as usize]; 0],
+            }
         }
     }
 }
@@ -336,10 +348,19 @@ cfg_if! {
 }
 
 s_no_extra_traits! {
+    // Internal, the `SIGEV_THREAD` arm with the C signature of the notification function.
+    struct sigev_thread {
+        function: Option<extern "C" fn(crate::sigval)>,
+        attribute: *mut crate::pthread_attr_t,
+    }
+
     pub union __c_anonymous_sigev_un {
         _pad: Padding<[c_int; SIGEV_PAD_SIZE]>,
         pub _tid: c_int,
+        // FIXME(1.0): `_function` has the wrong return type. Replace this arm with the private
+        // `sigev_thread` arm and remove `__c_anonymous_sigev_thread`.
         pub _sigev_thread: __c_anonymous_sigev_thread,
+        sigev_thread: sigev_thread,
     }
 
     pub struct sigevent {
@@ -355,6 +376,38 @@ s_no_extra_traits! {
     }
 }
 
+impl sigevent {
+    /// The thread that receives the signal when `sigev_notify` is [`SIGEV_THREAD_ID`].
+    pub unsafe fn sigev_notify_thread_id(&self) -> crate::pid_t {
+        self._sigev_un._tid
+    }
+
+    /// Sets the thread that receives the signal when `sigev_notify` is [`SIGEV_THREAD_ID`].
+    pub fn set_sigev_notify_thread_id(&mut self, tid: crate::pid_t) {
+        self._sigev_un._tid = tid;
+    }
+
+    /// The function called on a new thread when `sigev_notify` is [`SIGEV_THREAD`].
+    pub unsafe fn sigev_notify_function(&self) -> Option<extern "C" fn(crate::sigval)> {
+        self._sigev_un.sigev_thread.function
+    }
+
+    /// Sets the function called on a new thread when `sigev_notify` is [`SIGEV_THREAD`].
+    pub fn set_sigev_notify_function(&mut self, function: Option<extern "C" fn(crate::sigval)>) {
+        self._sigev_un.sigev_thread.function = function;
+    }
+
+    /// The attributes of the thread created when `sigev_notify` is [`SIGEV_THREAD`].
+    pub unsafe fn sigev_notify_attributes(&self) -> *mut crate::pthread_attr_t {
+        self._sigev_un.sigev_thread.attribute
+    }
+
+    /// Sets the attributes of the thread created when `sigev_notify` is [`SIGEV_THREAD`].
+    pub fn set_sigev_notify_attributes(&mut self, attributes: *mut crate::pthread_attr_t) {
+        self._sigev_un.sigev_thread.attribute = attributes;
+    }
+}
+
 cfg_if! {
     if #[cfg(not(target_os = "l4re"))] {
         s_no_extra_traits! {
@@ -424,6 +477,199 @@ cfg_if! {
     }
 }
 
+cfg_if! {
+    if #[cfg(not(target_os = "emscripten"))] {
+        s_no_extra_traits! {
+            // Internal, for casts to access union fields. These mirror the
+            // arms of the kernel's `union __sifields`, which all of the C
+            // libraries lay out identically.
+            struct sifields_kill {
+                si_pid: crate::pid_t,
+                si_uid: crate::uid_t,
+            }
+
+            // Internal, for casts to access union fields
+            struct sifields_timer {
+                si_tid: c_int,
+                si_overrun: c_int,
+                si_sigval: crate::sigval,
+            }
+
+            // Internal, for casts to access union fields
+            struct sifields_rt {
+                si_pid: crate::pid_t,
+                si_uid: crate::uid_t,
+                si_sigval: crate::sigval,
+            }
+
+            // Internal, for casts to access union fields
+            struct sifields_sigchld {
+                si_pid: crate::pid_t,
+                si_uid: crate::uid_t,
+                si_status: c_int,
+                si_utime: c_long,
+                si_stime: c_long,
+            }
+
+            // Internal, for casts to access union fields
+            struct sifields_addr_bnd {
+                si_lower: *mut c_void,
+                si_upper: *mut c_void,
+            }
+
+            // Internal, for casts to access union fields
+            union sifields_sigfault_bounds {
+                addr_bnd: sifields_addr_bnd,
+                pkey: u32,
+            }
+
+            // Internal, for casts to access union fields
+            struct sifields_sigfault {
+                si_addr: *mut c_void,
+                si_addr_lsb: c_short,
+                bounds: sifields_sigfault_bounds,
+            }
+
+            // Internal, for casts to access union fields. The kernel places
+            // these in the same union as `si_addr_lsb`, so they overlap it and
+            // the bounds.
+            struct sifields_sigfault_perf {
+                _si_addr: *mut c_void,
+                si_perf_data: c_ulong,
+                si_perf_type: u32,
+                si_perf_flags: u32,
+            }
+
+            // Internal, for casts to access union fields
+            struct sifields_sigpoll {
+                #[cfg(not(target_arch = "sparc64"))]
+                si_band: c_long,
+                #[cfg(target_arch = "sparc64")]
+                si_band: c_int,
+                si_fd: c_int,
+            }
+
+            // Internal, for casts to access union fields
+            struct sifields_sigsys {
+                si_call_addr: *mut c_void,
+                si_syscall: c_int,
+                si_arch: c_uint,
+            }
+
+            // Internal, for casts to access union fields
+            union sifields {
+                _align_pointer: *mut c_void,
+                kill: sifields_kill,
+                timer: sifields_timer,
+                rt: sifields_rt,
+                sigchld: sifields_sigchld,
+                sigfault: sifields_sigfault,
+                sigfault_perf: sifields_sigfault_perf,
+                sigpoll: sifields_sigpoll,
+                sigsys: sifields_sigsys,
+            }
+
+            // Internal, for casts to access union fields. Note that some variants
+            // of sifields start with a pointer, which makes the alignment of
+            // sifields vary on 32-bit and 64-bit architectures.
+            struct siginfo_f {
+                _siginfo_base: [c_int; 3],
+                sifields: sifields,
+            }
+        }
+
+        impl siginfo_t {
+            unsafe fn sifields(&self) -> &sifields {
+                &(*(self as *const siginfo_t).cast::<siginfo_f>()).sifields
+            }
+
+            pub unsafe fn si_pid(&self) -> crate::pid_t {
+                self.sifields().kill.si_pid
+            }
+
+            pub unsafe fn si_uid(&self) -> crate::uid_t {
+                self.sifields().kill.si_uid
+            }
+
+            pub unsafe fn si_timerid(&self) -> c_int {
+                self.sifields().timer.si_tid
+            }
+
+            pub unsafe fn si_overrun(&self) -> c_int {
+                self.sifields().timer.si_overrun
+            }
+
+            pub unsafe fn si_value(&self) -> crate::sigval {
+                self.sifields().rt.si_sigval
+            }
+
+            pub unsafe fn si_status(&self) -> c_int {
+                self.sifields().sigchld.si_status
+            }
+
+            pub unsafe fn si_utime(&self) -> c_long {
+                self.sifields().sigchld.si_utime
+            }
+
+            pub unsafe fn si_stime(&self) -> c_long {
+                self.sifields().sigchld.si_stime
+            }
+
+            pub unsafe fn si_addr(&self) -> *mut c_void {
+                self.sifields().sigfault.si_addr
+            }
+
+            pub unsafe fn si_addr_lsb(&self) -> c_short {
+                self.sifields().sigfault.si_addr_lsb
+            }
+
+            pub unsafe fn si_lower(&self) -> *mut c_void {
+                self.sifields().sigfault.bounds.addr_bnd.si_lower
+            }
+
+            pub unsafe fn si_upper(&self) -> *mut c_void {
+                self.sifields().sigfault.bounds.addr_bnd.si_upper
+            }
+
+            pub unsafe fn si_pkey(&self) -> u32 {
+                self.sifields().sigfault.bounds.pkey
+            }
+
+            pub unsafe fn si_perf_data(&self) -> c_ulong {
+                self.sifields().sigfault_perf.si_perf_data
+            }
+
+            pub unsafe fn si_perf_type(&self) -> u32 {
+                self.sifields().sigfault_perf.si_perf_type
+            }
+
+            pub unsafe fn si_perf_flags(&self) -> u32 {
+                self.sifields().sigfault_perf.si_perf_flags
+            }
+
+            pub unsafe fn si_band(&self) -> c_long {
+                self.sifields().sigpoll.si_band as c_long
+            }
+
+            pub unsafe fn si_fd(&self) -> c_int {
+                self.sifields().sigpoll.si_fd
+            }
+
+            pub unsafe fn si_call_addr(&self) -> *mut c_void {
+                self.sifields().sigsys.si_call_addr
+            }
+
+            pub unsafe fn si_syscall(&self) -> c_int {
+                self.sifields().sigsys.si_syscall
+            }
+
+            pub unsafe fn si_arch(&self) -> c_uint {
+                self.sifields().sigsys.si_arch
+            }
+        }
+    }
+}
+
 // intentionally not public, only used for fd_set
 cfg_if! {
     if #[cfg(target_pointer_width = "32")] {
@@ -843,6 +843,14 @@ pub const PRIO_PGRP: c_int = 1;
 pub const PRIO_USER: c_int = 2;
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno() = value }
+    }
+
     pub unsafe fn FD_CLR(fd: c_int, set: *mut fd_set) -> () {
         let bits = size_of_val(&(*set).fds_bits[0]) * 8;
         let fd = fd as usize;
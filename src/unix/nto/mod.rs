@@ -2329,6 +2329,14 @@ const fn _ALIGN(p: usize, b: usize) -> usize {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__get_errno_ptr() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__get_errno_ptr() = value }
+    }
+
     pub unsafe fn CMSG_FIRSTHDR(mhdr: *const msghdr) -> *mut cmsghdr {
         if (*mhdr).msg_controllen as usize >= size_of::<cmsghdr>() {
             (*mhdr).msg_control.cast()
@@ -652,6 +652,16 @@ pub const IPV6_UNICAST_HOPS: i32 = __SO_PROTOCOL + 6;
 pub const IPV6_V6ONLY: i32 = __SO_PROTOCOL + 7;
 pub const IPV6_RECVHOPLIMIT: i32 = __SO_PROTOCOL + 11;
 
+f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno() = value }
+    }
+}
+
 extern "C" {
     pub fn __errno() -> *mut c_int;
     pub fn bind(sockfd: i32, addr: *const sockaddr, addrlen: socklen_t) -> i32;
@@ -1114,6 +1114,14 @@ pub const PRIO_USER: c_int = 2;
 pub const RENAME_NOREPLACE: c_uint = 1;
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *__errno_location() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *__errno_location() = value }
+    }
+
     //sys/socket.h
     pub const unsafe fn CMSG_ALIGN(len: size_t) -> size_t {
         (len + size_of::<size_t>() - 1) & !(size_of::<size_t>() - 1)
@@ -2218,6 +2218,14 @@ const fn _CMSG_DATA_ALIGN(p: usize) -> usize {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *___errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *___errno() = value }
+    }
+
     pub unsafe fn CMSG_DATA(cmsg: *const cmsghdr) -> *mut c_uchar {
         _CMSG_DATA_ALIGN(cmsg.offset(1) as usize) as *mut c_uchar
     }
@@ -1459,6 +1459,16 @@ extern_ty! {
 }
 
 f! {
+    pub safe fn errno() -> c_int {
+        unsafe { errnoGet() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe {
+            errnoSet(value);
+        }
+    }
+
     pub const unsafe fn CMSG_ALIGN(len: usize) -> usize {
         len + size_of::<usize>() - 1 & !(size_of::<usize>() - 1)
     }
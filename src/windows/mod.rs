@@ -283,6 +283,16 @@ cfg_if! {
     }
 }
 
+f! {
+    pub safe fn errno() -> c_int {
+        unsafe { *_errno() }
+    }
+
+    pub safe fn set_errno(value: c_int) -> () {
+        unsafe { *_errno() = value }
+    }
+}
+
 extern "C" {
     pub fn isalnum(c: c_int) -> c_int;
     pub fn isalpha(c: c_int) -> c_int;
@@ -580,6 +590,7 @@ extern "C" {
         envstring: *const crate::wchar_t,
         value_string: *const crate::wchar_t,
     ) -> crate::errno_t;
+    pub fn _errno() -> *mut c_int;
 }
 
 extern "system" {